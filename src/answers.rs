@@ -0,0 +1,196 @@
+use crate::error::{parse_num, AocError, ErrorKind, Result};
+use crate::solution::{Answer, DynSolution};
+use std::collections::BTreeMap;
+
+/// Known answers for one day's input. Parts without a recorded answer are
+/// skipped when verifying.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Expected {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+/// The outcome of checking one part against its recorded answer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub part: u8,
+    pub expected: Answer,
+    pub actual: Answer,
+}
+
+impl Check {
+    pub fn ok(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+fn parse_value(line: &str, value: &str) -> Result<Answer> {
+    if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        Ok(Answer::Str(value[1..value.len() - 1].to_string()))
+    } else {
+        parse_num::<i64>(line, value).map(Answer::Int)
+    }
+}
+
+/// Parses an answers file: a `[dayNN]` table per day holding `part1` and
+/// `part2` keys, with integer or quoted string values.
+pub fn parse_answers(text: &str) -> Result<BTreeMap<usize, Expected>> {
+    let mut answers: BTreeMap<usize, Expected> = BTreeMap::new();
+    let mut current: Option<usize> = None;
+    for line in text.lines() {
+        let located = |e: AocError| e.within(text, line);
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = trimmed.strip_prefix('[') {
+            let day_slice = header
+                .strip_suffix(']')
+                .and_then(|x| x.strip_prefix("day"))
+                .ok_or_else(|| {
+                    located(AocError::at(
+                        line,
+                        trimmed,
+                        ErrorKind::Invalid("expected a `[dayNN]` table".to_string()),
+                    ))
+                })?;
+            let day = parse_num(line, day_slice).map_err(located)?;
+            if crate::solution(day).is_none() {
+                return Err(located(AocError::at(
+                    line,
+                    day_slice,
+                    ErrorKind::Invalid(format!("there is no day {}", day)),
+                )));
+            }
+            answers.entry(day).or_default();
+            current = Some(day);
+            continue;
+        }
+
+        let equals = trimmed
+            .find('=')
+            .ok_or_else(|| located(AocError::at_end(line, ErrorKind::Missing("`=`"))))?;
+        let key = trimmed[..equals].trim();
+        let value = parse_value(line, trimmed[equals + 1..].trim()).map_err(located)?;
+        let expected = match current {
+            Some(day) => answers.get_mut(&day).unwrap(),
+            None => {
+                return Err(located(AocError::at(
+                    line,
+                    key,
+                    ErrorKind::Invalid("answer outside of a `[dayNN]` table".to_string()),
+                )))
+            }
+        };
+        match key {
+            "part1" => expected.part1 = Some(value),
+            "part2" => expected.part2 = Some(value),
+            _ => {
+                return Err(located(AocError::at(
+                    line,
+                    key,
+                    ErrorKind::Unknown {
+                        what: "key",
+                        token: key.to_string(),
+                    },
+                )))
+            }
+        }
+    }
+    Ok(answers)
+}
+
+fn format_value(answer: &Answer) -> String {
+    match answer {
+        Answer::Str(s) => format!("\"{}\"", s),
+        other => other.to_string(),
+    }
+}
+
+/// Formats a `[dayNN]` table that `parse_answers` reads back.
+pub fn to_toml(day: usize, expected: &Expected) -> String {
+    let mut text = format!("[day{:02}]\n", day);
+    for (key, answer) in [("part1", &expected.part1), ("part2", &expected.part2)].iter() {
+        if let Some(answer) = answer {
+            text += &format!("{} = {}\n", key, format_value(answer));
+        }
+    }
+    text
+}
+
+/// Solves `day` for `input` and compares each part that has a recorded
+/// answer.
+pub fn verify_day(day: usize, input: &str, expected: &Expected) -> Result<Vec<Check>> {
+    verify_with(
+        crate::solution(day).expect("no solution for day"),
+        input,
+        expected,
+    )
+}
+
+/// Like `verify_day`, but with `solution` in place of the day's own.
+pub fn verify_with(
+    solution: &dyn DynSolution,
+    input: &str,
+    expected: &Expected,
+) -> Result<Vec<Check>> {
+    let parsed = solution.parse_dyn(input)?;
+    let mut checks = Vec::new();
+    if let Some(answer) = &expected.part1 {
+        checks.push(Check {
+            part: 1,
+            expected: answer.clone(),
+            actual: solution.part1_dyn(parsed.as_ref()),
+        });
+    }
+    if let Some(answer) = &expected.part2 {
+        checks.push(Check {
+            part: 2,
+            expected: answer.clone(),
+            actual: solution.part2_dyn(parsed.as_ref()),
+        });
+    }
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let text = r#"# comment
+
+[day02]
+part1 = 500
+part2 = 313
+
+[day21]
+part2 = "a,b,c"
+"#;
+        let answers = parse_answers(text).unwrap();
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers[&2].part1, Some(Answer::Int(500)));
+        assert_eq!(answers[&21].part1, None);
+        assert_eq!(answers[&21].part2, Some(Answer::from("a,b,c")));
+
+        let err = parse_answers("[day02]\npart3 = 1").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: unknown key `part3`");
+        let err = parse_answers("[day02]\npart1 = 1x").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 9: invalid number `1x`");
+        let err = parse_answers("[day26]").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 5: there is no day 26");
+    }
+
+    #[test]
+    fn test_to_toml() {
+        let expected = Expected {
+            part1: None,
+            part2: Some(Answer::from("a,b")),
+        };
+        let text = to_toml(21, &expected);
+        assert_eq!(text, "[day21]\npart2 = \"a,b\"\n");
+        assert_eq!(parse_answers(&text).unwrap()[&21], expected);
+    }
+}
@@ -0,0 +1,393 @@
+use crate::error::{parse_lines, parse_num, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use std::cmp::Ordering;
+
+pub fn parse(file_contents: &str) -> Result<Vec<i32>> {
+    parse_lines(file_contents, |line| parse_num(line, line))
+}
+
+/// Every way to pick `k` entries at distinct positions that add up to
+/// `target`, each given as its values in ascending order. Entries with the
+/// same value are interchangeable, so each combination of values is listed
+/// once.
+pub fn find_k_sum(numbers: &[i32], k: usize, target: i64) -> Vec<Vec<i32>> {
+    let mut sorted = numbers.to_vec();
+    sorted.sort_unstable();
+    let mut found = Vec::new();
+    k_sum(&sorted, k, target, &mut Vec::new(), &mut found);
+    found
+}
+
+/// Sorted search: fixes the smallest entry and recurses, down to a
+/// two-pointer scan for the last two, so it takes O(n^(k-1)) rather than
+/// O(n^k).
+fn k_sum(sorted: &[i32], k: usize, target: i64, picked: &mut Vec<i32>, found: &mut Vec<Vec<i32>>) {
+    if k == 0 {
+        if target == 0 {
+            found.push(picked.clone());
+        }
+        return;
+    }
+    if sorted.len() < k {
+        return;
+    }
+    let sum = |xs: &[i32]| xs.iter().map(|&x| x as i64).sum::<i64>();
+    if target < sum(&sorted[..k]) || target > sum(&sorted[sorted.len() - k..]) {
+        return;
+    }
+
+    if k == 2 {
+        let (mut lo, mut hi) = (0, sorted.len() - 1);
+        while lo < hi {
+            let (a, b) = (sorted[lo], sorted[hi]);
+            match (a as i64 + b as i64).cmp(&target) {
+                Ordering::Less => lo += 1,
+                Ordering::Greater => hi -= 1,
+                Ordering::Equal => {
+                    let mut combination = picked.clone();
+                    combination.extend_from_slice(&[a, b]);
+                    found.push(combination);
+                    while lo < hi && sorted[lo] == a {
+                        lo += 1;
+                    }
+                    while lo < hi && sorted[hi] == b {
+                        hi -= 1;
+                    }
+                }
+            }
+        }
+        return;
+    }
+
+    for i in 0..=sorted.len() - k {
+        if i > 0 && sorted[i] == sorted[i - 1] {
+            continue;
+        }
+        picked.push(sorted[i]);
+        k_sum(
+            &sorted[i + 1..],
+            k - 1,
+            target - sorted[i] as i64,
+            picked,
+            found,
+        );
+        picked.pop();
+    }
+}
+
+/// Up to `limit` subsets of any size whose entries add up to `target`, each
+/// given as its values in ascending order and, as with `find_k_sum`, listed
+/// once per combination of values. Entries must not be negative.
+///
+/// A table of which sums each prefix of the distinct values can reach is
+/// built first, so the reconstruction only follows choices that lead to a
+/// solution. That takes O(n * target) time and bits of memory.
+pub fn find_subset_sums(numbers: &[i32], target: i64, limit: usize) -> Vec<Vec<i32>> {
+    assert!(
+        numbers.iter().all(|&x| x >= 0),
+        "subset sums need entries that aren't negative"
+    );
+    let total = numbers.iter().map(|&x| x as i64).sum::<i64>();
+    if target < 0 || target > total || limit == 0 {
+        return Vec::new();
+    }
+
+    // Each distinct value with how many times it appears.
+    let mut sorted = numbers.to_vec();
+    sorted.sort_unstable();
+    let mut groups: Vec<(usize, usize)> = Vec::new();
+    for &x in sorted.iter() {
+        match groups.last_mut() {
+            Some((value, count)) if *value == x as usize => *count += 1,
+            _ => groups.push((x as usize, 1)),
+        }
+    }
+
+    // reach[g] has bit `s` set when the first `g` groups can add up to `s`.
+    let target = target as usize;
+    let words = target / 64 + 1;
+    let get = |row: &[u64], s: usize| row[s / 64] >> (s % 64) & 1 == 1;
+    let mut reach = vec![vec![0u64; words]];
+    reach[0][0] = 1;
+    for &(value, count) in groups.iter() {
+        let prev = reach.last().unwrap();
+        let mut row = prev.clone();
+        for s in (0..=target).filter(|&s| get(prev, s)) {
+            for c in 1..=count {
+                let sum = s + c * value;
+                if sum > target {
+                    break;
+                }
+                row[sum / 64] |= 1 << (sum % 64);
+            }
+        }
+        reach.push(row);
+    }
+
+    let mut found = Vec::new();
+    if get(&reach[groups.len()], target) {
+        let mut picked = Vec::new();
+        subsets(
+            &groups,
+            &reach,
+            groups.len(),
+            target,
+            limit,
+            &mut picked,
+            &mut found,
+        );
+    }
+    found
+}
+
+/// Walks back from the last group, trying each count of its value that
+/// leaves a sum the earlier groups can reach.
+fn subsets(
+    groups: &[(usize, usize)],
+    reach: &[Vec<u64>],
+    g: usize,
+    sum: usize,
+    limit: usize,
+    picked: &mut Vec<i32>,
+    found: &mut Vec<Vec<i32>>,
+) {
+    if g == 0 {
+        found.push(picked.iter().rev().cloned().collect());
+        return;
+    }
+    let (value, count) = groups[g - 1];
+    for c in 0..=count {
+        if c * value > sum || found.len() >= limit {
+            break;
+        }
+        let rest = sum - c * value;
+        if reach[g - 1][rest / 64] >> (rest % 64) & 1 == 1 {
+            picked.extend((0..c).map(|_| value as i32));
+            subsets(groups, reach, g - 1, rest, limit, picked, found);
+            picked.truncate(picked.len() - c);
+        }
+    }
+}
+
+/// The exact product of `values`, however many there are, in decimal.
+pub fn product(values: &[i32]) -> String {
+    // Little-endian base 10^9 digits.
+    const BASE: u64 = 1_000_000_000;
+    let mut digits: Vec<u64> = vec![1];
+    for &x in values.iter() {
+        let mut carry = 0;
+        for d in digits.iter_mut() {
+            let v = *d * x.unsigned_abs() as u64 + carry;
+            *d = v % BASE;
+            carry = v / BASE;
+        }
+        while carry > 0 {
+            digits.push(carry % BASE);
+            carry /= BASE;
+        }
+    }
+    while digits.len() > 1 && *digits.last().unwrap() == 0 {
+        digits.pop();
+    }
+
+    let negative = digits != [0] && values.iter().filter(|&&x| x < 0).count() % 2 == 1;
+    let mut text = if negative {
+        "-".to_string()
+    } else {
+        String::new()
+    };
+    text += &digits.last().unwrap().to_string();
+    for d in digits.iter().rev().skip(1) {
+        text += &format!("{:09}", d);
+    }
+    text
+}
+
+/// The product of the first combination of `k` entries adding up to 2020.
+fn solve(numbers: &[i32], k: usize) -> Option<i64> {
+    find_k_sum(numbers, k, 2020)
+        .first()
+        .map(|c| c.iter().map(|&x| x as i64).product())
+}
+
+/// `size` expenses with exactly one pair and one triplet summing to 2020.
+/// Fillers are all over 1500 so they can't take part in either.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let (a, b, c, d) = loop {
+        let a = rng.range(1011, 1499) as i32;
+        let b = rng.range(521, 978) as i32;
+        let c = rng.range((1042 - b).max(521) as i64, (1499 - b).min(978) as i64) as i32;
+        let planted = [a, 2020 - a, b, c, 2020 - b - c];
+        let distinct = planted
+            .iter()
+            .enumerate()
+            .all(|(i, x)| !planted[i + 1..].contains(x));
+        if distinct {
+            break (a, b, c, 2020 - b - c);
+        }
+    };
+    let mut numbers = vec![a, 2020 - a, b, c, d];
+    while numbers.len() < size {
+        numbers.push(rng.range(1501, 2019) as i32);
+    }
+    rng.shuffle(&mut numbers);
+    let input: String = numbers.iter().map(|x| format!("{}\n", x)).collect();
+    Generated::new(input).part1(a * (2020 - a)).part2(b * c * d)
+}
+
+static EXAMPLE: &str = "1721\n979\n366\n299\n675\n1456\n";
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, numbers: &Self::Parsed) -> Answer {
+        solve(numbers, 2).into()
+    }
+
+    fn part2(&self, numbers: &Self::Parsed) -> Answer {
+        solve(numbers, 3).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(514579).part2(241861950)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{compare, shrink_vec};
+
+    #[test]
+    fn test() {
+        let numbers = parse(EXAMPLE).unwrap();
+        assert_eq!(find_k_sum(&numbers, 2, 2020), vec![vec![299, 1721]]);
+        assert_eq!(find_k_sum(&numbers, 3, 2020), vec![vec![366, 675, 979]]);
+    }
+
+    #[test]
+    fn test_distinct_entries() {
+        assert!(find_k_sum(&[1010, 3], 2, 2020).is_empty());
+        assert_eq!(
+            find_k_sum(&[1010, 3, 1010], 2, 2020),
+            vec![vec![1010, 1010]]
+        );
+        assert_eq!(
+            find_k_sum(&[5, -1, 4, 0, 1, 5], 2, 5),
+            vec![vec![0, 5], vec![1, 4]]
+        );
+        assert_eq!(find_k_sum(&[1, 2], 0, 0), vec![Vec::<i32>::new()]);
+        assert!(find_k_sum(&[1, 2], 3, 3).is_empty());
+    }
+
+    /// Every combination of `k` positions, deduplicated by value.
+    fn brute_force(numbers: &[i32], k: usize, target: i64) -> Vec<Vec<i32>> {
+        let mut found = Vec::new();
+        let mut stack = vec![(0, Vec::new())];
+        while let Some((start, picked)) = stack.pop() {
+            if picked.len() == k {
+                if picked.iter().map(|&x: &i32| x as i64).sum::<i64>() == target {
+                    let mut values = picked;
+                    values.sort_unstable();
+                    found.push(values);
+                }
+                continue;
+            }
+            for (i, &x) in numbers.iter().enumerate().skip(start) {
+                let mut next = picked.clone();
+                next.push(x);
+                stack.push((i + 1, next));
+            }
+        }
+        found.sort();
+        found.dedup();
+        found
+    }
+
+    #[test]
+    fn test_subset_sums() {
+        let numbers = parse(EXAMPLE).unwrap();
+        assert_eq!(
+            find_subset_sums(&numbers, 2020, 10),
+            vec![vec![366, 675, 979], vec![299, 1721]]
+        );
+        assert_eq!(find_subset_sums(&numbers, 2020, 1).len(), 1);
+        assert_eq!(find_subset_sums(&[2, 2, 3], 4, 10), vec![vec![2, 2]]);
+        assert_eq!(find_subset_sums(&[0, 1], 1, 10), vec![vec![1], vec![0, 1]]);
+        assert!(find_subset_sums(&[1, 2], 4, 10).is_empty());
+        assert!(find_subset_sums(&[1, 2], -1, 10).is_empty());
+    }
+
+    #[test]
+    fn test_product() {
+        assert_eq!(product(&[299, 1721]), "514579");
+        assert_eq!(product(&[]), "1");
+        assert_eq!(product(&[-2, 3]), "-6");
+        assert_eq!(product(&[-2, 0]), "0");
+        assert_eq!(
+            product(&[1_000_000_000, 1_000_000_000, 7]),
+            "7000000000000000000"
+        );
+        assert_eq!(product(&[2020; 3]), "8242408000");
+    }
+
+    #[test]
+    fn test_differential() {
+        let generate = |rng: &mut Rng| {
+            let numbers: Vec<i32> = (0..rng.range(0, 9))
+                .map(|_| rng.range(-5, 10) as i32)
+                .collect();
+            (numbers, rng.range(0, 4) as usize, rng.range(-5, 20))
+        };
+        let shrink = |(numbers, k, target): &(Vec<i32>, usize, i64)| {
+            shrink_vec(numbers)
+                .into_iter()
+                .map(|n| (n, *k, *target))
+                .collect()
+        };
+        let fast = |(n, k, t): &(Vec<i32>, usize, i64)| find_k_sum(n, *k, *t);
+        let slow = |(n, k, t): &(Vec<i32>, usize, i64)| brute_force(n, *k, *t);
+        if let Err(d) = compare(generate, shrink, fast, slow) {
+            panic!("find_k_sum disagrees with brute force, {}", d);
+        }
+    }
+
+    #[test]
+    fn test_differential_subsets() {
+        let generate = |rng: &mut Rng| {
+            let numbers: Vec<i32> = (0..rng.range(0, 9))
+                .map(|_| rng.range(0, 10) as i32)
+                .collect();
+            (numbers, rng.range(0, 30))
+        };
+        let shrink = |(numbers, target): &(Vec<i32>, i64)| {
+            shrink_vec(numbers)
+                .into_iter()
+                .map(|n| (n, *target))
+                .collect()
+        };
+        let sorted = |mut found: Vec<Vec<i32>>| {
+            found.sort();
+            found
+        };
+        let dp = |(n, t): &(Vec<i32>, i64)| sorted(find_subset_sums(n, *t, usize::MAX));
+        let slow = |(n, t): &(Vec<i32>, i64)| {
+            let found = (0..=n.len()).flat_map(|k| brute_force(n, k, *t)).collect();
+            sorted(found)
+        };
+        if let Err(d) = compare(generate, shrink, dp, slow) {
+            panic!("find_subset_sums disagrees with brute force, {}", d);
+        }
+    }
+}
@@ -0,0 +1,752 @@
+use crate::csv;
+use crate::error::{parse_lines, parse_num, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A rule that passwords have to follow. Its `Display` is the spec that
+/// `parse_policy` reads back.
+pub trait PasswordPolicy: fmt::Display {
+    /// Why `password` can't be judged by this policy at all, if it can't,
+    /// e.g. because a position is past its end.
+    fn applies(&self, _password: &str) -> std::result::Result<(), String> {
+        Ok(())
+    }
+
+    /// Why `password` breaks the policy, if it does. Only meaningful when
+    /// the policy `applies`.
+    fn check(&self, password: &str) -> std::result::Result<(), String>;
+
+    fn allows(&self, password: &str) -> bool {
+        self.applies(password).is_ok() && self.check(password).is_ok()
+    }
+}
+
+/// The part 1 policy: `letter` appears between `min` and `max` times.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CountRange {
+    pub min: usize,
+    pub max: usize,
+    pub letter: char,
+}
+
+impl PasswordPolicy for CountRange {
+    fn check(&self, password: &str) -> std::result::Result<(), String> {
+        let count = password.chars().filter(|&x| x == self.letter).count();
+        if self.min <= count && count <= self.max {
+            Ok(())
+        } else {
+            Err(format!(
+                "`{}` appears {} times, not {}-{}",
+                self.letter, count, self.min, self.max
+            ))
+        }
+    }
+}
+
+impl fmt::Display for CountRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "count {}-{} {}", self.min, self.max, self.letter)
+    }
+}
+
+/// The part 2 policy: `letter` is at exactly one of the 1-based positions
+/// `pos1` and `pos2`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionXor {
+    pub pos1: usize,
+    pub pos2: usize,
+    pub letter: char,
+}
+
+impl PasswordPolicy for PositionXor {
+    fn applies(&self, password: &str) -> std::result::Result<(), String> {
+        let len = password.chars().count();
+        match [self.pos1, self.pos2]
+            .iter()
+            .find(|&&pos| pos < 1 || pos > len)
+        {
+            Some(pos) => Err(format!(
+                "position {} is outside the password's 1-{}",
+                pos, len
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn check(&self, password: &str) -> std::result::Result<(), String> {
+        let at = |pos: usize| password.chars().nth(pos.wrapping_sub(1)) == Some(self.letter);
+        match (at(self.pos1), at(self.pos2)) {
+            (true, true) => Err(format!(
+                "`{}` is at both {} and {}",
+                self.letter, self.pos1, self.pos2
+            )),
+            (false, false) => Err(format!(
+                "`{}` is at neither {} nor {}",
+                self.letter, self.pos1, self.pos2
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for PositionXor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "positions {}-{} {}", self.pos1, self.pos2, self.letter)
+    }
+}
+
+/// No letter appears more than once.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoRepeatedLetter;
+
+impl PasswordPolicy for NoRepeatedLetter {
+    fn check(&self, password: &str) -> std::result::Result<(), String> {
+        let mut counts: HashMap<char, usize> = HashMap::new();
+        for c in password.chars() {
+            *counts.entry(c).or_default() += 1;
+        }
+        match password.chars().find(|c| counts[c] > 1) {
+            Some(c) => Err(format!("`{}` appears {} times", c, counts[&c])),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for NoRepeatedLetter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no-repeat")
+    }
+}
+
+/// At least `min` different letters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistinctLetters {
+    pub min: usize,
+}
+
+impl PasswordPolicy for DistinctLetters {
+    fn check(&self, password: &str) -> std::result::Result<(), String> {
+        let mut letters: Vec<char> = password.chars().collect();
+        letters.sort_unstable();
+        letters.dedup();
+        if letters.len() >= self.min {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} distinct letters, not at least {}",
+                letters.len(),
+                self.min
+            ))
+        }
+    }
+}
+
+impl fmt::Display for DistinctLetters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "distinct {}", self.min)
+    }
+}
+
+/// The whole password matches `pattern`.
+#[derive(Debug, Clone)]
+pub struct RegexPolicy {
+    pub pattern: String,
+    /// `pattern` anchored at both ends.
+    pub re: Regex,
+}
+
+impl PasswordPolicy for RegexPolicy {
+    fn check(&self, password: &str) -> std::result::Result<(), String> {
+        if self.re.is_match(password) {
+            Ok(())
+        } else {
+            Err(format!("doesn't match `{}`", self.pattern))
+        }
+    }
+}
+
+impl fmt::Display for RegexPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "regex {}", self.pattern)
+    }
+}
+
+/// The `lo-hi letter` that the puzzle puts before each password.
+fn parse_numbers(src: &str, text: &str) -> Result<(usize, usize, char)> {
+    let expected = || {
+        AocError::at(
+            src,
+            text,
+            ErrorKind::Invalid("expected `min-max letter`".to_string()),
+        )
+    };
+    let digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let (numbers, letter) = text.split_once(' ').ok_or_else(expected)?;
+    let (lo, hi) = numbers.split_once('-').ok_or_else(expected)?;
+    let mut letters = letter.chars();
+    match (letters.next(), letters.next()) {
+        (Some(c @ 'a'..='z'), None) if digits(lo) && digits(hi) => {
+            Ok((parse_num(src, lo)?, parse_num(src, hi)?, c))
+        }
+        _ => Err(expected()),
+    }
+}
+
+/// Reads a policy spec, a slice of `src`: `count min-max letter`,
+/// `positions pos1-pos2 letter`, `no-repeat`, `distinct n` or
+/// `regex pattern`.
+pub fn parse_policy(src: &str, spec: &str) -> Result<Box<dyn PasswordPolicy>> {
+    let spec = spec.trim();
+    let (name, args) = match spec.find(' ') {
+        Some(i) => (&spec[..i], spec[i + 1..].trim_start()),
+        None => (spec, &spec[spec.len()..]),
+    };
+    let no_args = |policy: Box<dyn PasswordPolicy>| {
+        if args.is_empty() {
+            Ok(policy)
+        } else {
+            Err(AocError::at(
+                src,
+                args,
+                ErrorKind::Invalid(format!("`{}` takes no arguments", name)),
+            ))
+        }
+    };
+    match name {
+        "count" => {
+            let (min, max, letter) = parse_numbers(src, args)?;
+            Ok(Box::new(CountRange { min, max, letter }))
+        }
+        "positions" => {
+            let (pos1, pos2, letter) = parse_numbers(src, args)?;
+            Ok(Box::new(PositionXor { pos1, pos2, letter }))
+        }
+        "no-repeat" => no_args(Box::new(NoRepeatedLetter)),
+        "distinct" => {
+            if args.is_empty() {
+                return Err(AocError::at(src, args, ErrorKind::Missing("count")));
+            }
+            let min = parse_num(src, args)?;
+            Ok(Box::new(DistinctLetters { min }))
+        }
+        "regex" => {
+            let re = Regex::new(&format!("^(?:{})$", args))
+                .map_err(|e| AocError::at(src, args, ErrorKind::Invalid(e.to_string())))?;
+            Ok(Box::new(RegexPolicy {
+                pattern: args.to_string(),
+                re,
+            }))
+        }
+        _ => Err(AocError::at(
+            src,
+            name,
+            ErrorKind::Unknown {
+                what: "policy",
+                token: name.to_string(),
+            },
+        )),
+    }
+}
+
+/// What a line says about the password after it.
+pub enum LinePolicy {
+    /// The puzzle's `lo-hi letter`, read by whichever policy the run uses.
+    Numbers { lo: usize, hi: usize, letter: char },
+    /// A policy named at the start of the line, which overrides the run's.
+    Named(Box<dyn PasswordPolicy>),
+}
+
+/// One line of the password database.
+pub struct Entry {
+    pub policy: LinePolicy,
+    pub password: String,
+}
+
+/// The policy a run applies to lines that don't name their own.
+pub enum RunPolicy {
+    /// Read each line's numbers as a `CountRange`.
+    Count,
+    /// Read each line's numbers as a `PositionXor`.
+    Positions,
+    /// Ignore the numbers and check every such line against one policy.
+    Fixed(Box<dyn PasswordPolicy>),
+}
+
+impl RunPolicy {
+    /// `count` and `positions` read each line's numbers; any other spec
+    /// goes to `parse_policy`.
+    pub fn parse(spec: &str) -> Result<RunPolicy> {
+        match spec.trim() {
+            "count" => Ok(RunPolicy::Count),
+            "positions" => Ok(RunPolicy::Positions),
+            _ => parse_policy(spec, spec).map(RunPolicy::Fixed),
+        }
+    }
+}
+
+/// How a password fared against its policy.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Valid,
+    /// The password breaks the policy, for this reason.
+    Invalid(String),
+    /// The line couldn't be checked: it's malformed, or the policy can't
+    /// judge its password.
+    Error(String),
+}
+
+/// Checks `entry` against its own policy or else the run's, returning the
+/// policy used and how the password fared.
+pub fn check_entry(run: &RunPolicy, entry: &Entry) -> (String, Status) {
+    let check = |policy: &dyn PasswordPolicy| {
+        let status = match policy.applies(&entry.password) {
+            Err(e) => Status::Error(e),
+            Ok(()) => match policy.check(&entry.password) {
+                Ok(()) => Status::Valid,
+                Err(reason) => Status::Invalid(reason),
+            },
+        };
+        (policy.to_string(), status)
+    };
+    match (&entry.policy, run) {
+        (LinePolicy::Named(policy), _) => check(policy.as_ref()),
+        (LinePolicy::Numbers { .. }, RunPolicy::Fixed(policy)) => check(policy.as_ref()),
+        (&LinePolicy::Numbers { lo, hi, letter }, RunPolicy::Count) => check(&CountRange {
+            min: lo,
+            max: hi,
+            letter,
+        }),
+        (&LinePolicy::Numbers { lo, hi, letter }, RunPolicy::Positions) => check(&PositionXor {
+            pos1: lo,
+            pos2: hi,
+            letter,
+        }),
+    }
+}
+
+/// Parses a line: a policy, `: ` and the password. The policy is either the
+/// puzzle's `lo-hi letter` or a spec for `parse_policy`.
+fn parse_line(line: &str) -> Result<Entry> {
+    let colon = line.rfind(": ").ok_or_else(|| {
+        AocError::at(
+            line,
+            line,
+            ErrorKind::Invalid("expected `min-max letter: password`".to_string()),
+        )
+    })?;
+    let (policy_text, password) = (&line[..colon], &line[colon + 2..]);
+    if password.is_empty() || password.contains(char::is_whitespace) {
+        return Err(AocError::at(
+            line,
+            password,
+            ErrorKind::Invalid("expected a password without spaces".to_string()),
+        ));
+    }
+    let policy = if policy_text.starts_with(|c: char| c.is_ascii_digit()) {
+        let (lo, hi, letter) = parse_numbers(line, policy_text)?;
+        LinePolicy::Numbers { lo, hi, letter }
+    } else {
+        LinePolicy::Named(parse_policy(line, policy_text)?)
+    };
+    Ok(Entry {
+        policy,
+        password: password.to_string(),
+    })
+}
+
+pub fn parse(file_contents: &str) -> Result<Vec<Entry>> {
+    parse_lines(file_contents, parse_line)
+}
+
+/// How many passwords pass their line's policy or else `run`.
+pub fn count_valid(run: &RunPolicy, entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .filter(|e| check_entry(run, e).1 == Status::Valid)
+        .count()
+}
+
+/// The validation of one line of a password database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineReport {
+    /// 1-based.
+    pub line: usize,
+    /// The policy checked, or `None` if the line didn't parse.
+    pub policy: Option<String>,
+    pub status: Status,
+}
+
+/// Validates every non-empty line of `text` on its own, so malformed lines
+/// are reported rather than stopping the rest.
+pub fn validate(text: &str, run: &RunPolicy) -> Vec<LineReport> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| match parse_line(line) {
+            Ok(entry) => {
+                let (policy, status) = check_entry(run, &entry);
+                LineReport {
+                    line: i + 1,
+                    policy: Some(policy),
+                    status,
+                }
+            }
+            Err(e) => LineReport {
+                line: i + 1,
+                policy: None,
+                status: Status::Error(format!("column {}: {}", e.column, e.kind)),
+            },
+        })
+        .collect()
+}
+
+/// One line per report, e.g. `line 2: invalid (count 1-3 b): ...`.
+pub fn report_text(reports: &[LineReport]) -> String {
+    let mut text = String::new();
+    for r in reports.iter() {
+        let policy = match &r.policy {
+            Some(p) => format!(" ({})", p),
+            None => String::new(),
+        };
+        text += &match &r.status {
+            Status::Valid => format!("line {}: valid{}\n", r.line, policy),
+            Status::Invalid(why) => format!("line {}: invalid{}: {}\n", r.line, policy, why),
+            Status::Error(why) => format!("line {}: error{}: {}\n", r.line, policy, why),
+        };
+    }
+    text
+}
+
+/// The reports as CSV with a `line,status,policy,reason` header.
+pub fn report_csv(reports: &[LineReport]) -> String {
+    let mut text = csv::row(&["line", "status", "policy", "reason"]);
+    for r in reports.iter() {
+        let (status, reason) = match &r.status {
+            Status::Valid => ("valid", ""),
+            Status::Invalid(why) => ("invalid", why.as_str()),
+            Status::Error(why) => ("error", why.as_str()),
+        };
+        let line = r.line.to_string();
+        let policy = r.policy.as_deref().unwrap_or("");
+        text += &csv::row(&[line.as_str(), status, policy, reason]);
+    }
+    text
+}
+
+/// Which of the puzzle's readings of `lo-hi letter` a corpus is made for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorpusPolicy {
+    Count,
+    Positions,
+}
+
+/// Settings for `generate_corpus`.
+#[derive(Debug, Clone)]
+pub struct CorpusOptions {
+    pub policy: CorpusPolicy,
+    /// Lowercase letters to build passwords from; at least two.
+    pub alphabet: Vec<char>,
+    /// Inclusive bounds on password length.
+    pub min_len: usize,
+    pub max_len: usize,
+    /// The chance that each password satisfies its policy.
+    pub valid_ratio: f64,
+}
+
+impl Default for CorpusOptions {
+    fn default() -> CorpusOptions {
+        CorpusOptions {
+            policy: CorpusPolicy::Count,
+            alphabet: ('a'..='z').collect(),
+            min_len: 2,
+            max_len: 20,
+            valid_ratio: 0.5,
+        }
+    }
+}
+
+impl CorpusOptions {
+    /// Why these options can't make a corpus, if they can't.
+    pub fn problem(&self) -> Option<String> {
+        let mut letters = self.alphabet.clone();
+        letters.sort_unstable();
+        letters.dedup();
+        if letters.len() < 2 || letters.iter().any(|c| !c.is_ascii_lowercase()) {
+            Some("the alphabet needs at least two lowercase letters".to_string())
+        } else if self.min_len < 2 {
+            Some("passwords need to be at least 2 long".to_string())
+        } else if self.min_len > self.max_len {
+            Some(format!("no lengths in {}-{}", self.min_len, self.max_len))
+        } else if !(0.0..=1.0).contains(&self.valid_ratio) {
+            Some("the valid ratio has to be between 0 and 1".to_string())
+        } else {
+            None
+        }
+    }
+}
+
+/// `count` lines in the puzzle's `lo-hi letter: password` format, each
+/// labelled with whether its password is valid under `options.policy`. The
+/// label is decided first and the password built to match, so it always
+/// holds.
+pub fn generate_corpus(
+    rng: &mut Rng,
+    options: &CorpusOptions,
+    count: usize,
+) -> Vec<(String, bool)> {
+    assert_eq!(options.problem(), None);
+    let mut corpus = Vec::with_capacity(count);
+    for _ in 0..count {
+        let valid = rng.chance(options.valid_ratio);
+        let len = rng.range(options.min_len as i64, options.max_len as i64) as usize;
+        let letter = *rng.choose(&options.alphabet);
+        let others: Vec<char> = options
+            .alphabet
+            .iter()
+            .cloned()
+            .filter(|&c| c != letter)
+            .collect();
+        let (lo, hi, password) = match options.policy {
+            CorpusPolicy::Count => {
+                let lo = rng.range(1, len as i64) as usize;
+                let hi = rng.range(lo as i64, len as i64) as usize;
+                // Too few is always possible since `lo` is at least 1.
+                let count = if valid {
+                    rng.range(lo as i64, hi as i64)
+                } else if hi < len && rng.chance(0.5) {
+                    rng.range(hi as i64 + 1, len as i64)
+                } else {
+                    rng.range(0, lo as i64 - 1)
+                } as usize;
+                let mut password: Vec<char> = (0..len)
+                    .map(|i| {
+                        if i < count {
+                            letter
+                        } else {
+                            *rng.choose(&others)
+                        }
+                    })
+                    .collect();
+                rng.shuffle(&mut password);
+                (lo, hi, password)
+            }
+            CorpusPolicy::Positions => {
+                let lo = rng.range(1, len as i64 - 1) as usize;
+                let hi = rng.range(lo as i64 + 1, len as i64) as usize;
+                let mut password: Vec<char> =
+                    (0..len).map(|_| *rng.choose(&options.alphabet)).collect();
+                let at_lo = rng.chance(0.5);
+                let at_hi = if valid { !at_lo } else { at_lo };
+                for &(pos, at) in [(lo, at_lo), (hi, at_hi)].iter() {
+                    password[pos - 1] = if at { letter } else { *rng.choose(&others) };
+                }
+                (lo, hi, password)
+            }
+        };
+        let password: String = password.into_iter().collect();
+        corpus.push((format!("{}-{} {}: {}", lo, hi, letter, password), valid));
+    }
+    corpus
+}
+
+/// `size` random policies. Passwords mostly use a few letters so that the
+/// policies' letter turns up often enough for both outcomes.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let (mut valid1, mut valid2) = (0, 0);
+    for _ in 0..size {
+        let len = rng.range(2, 20) as usize;
+        let lo = rng.range(1, len as i64 - 1) as usize;
+        let hi = rng.range(lo as i64 + 1, len as i64) as usize;
+        let letter = rng.string("abcdefghijklmnopqrstuvwxyz", 1);
+        let password = rng.string(&format!("{}{}{}xyz", letter, letter, letter), len);
+        let count = password.matches(&letter).count();
+        valid1 += (lo <= count && count <= hi) as usize;
+        let at = |i: usize| password[i - 1..i] == letter;
+        valid2 += (at(lo) != at(hi)) as usize;
+        input += &format!("{}-{} {}: {}\n", lo, hi, letter, password);
+    }
+    Generated::new(input).part1(valid1).part2(valid2)
+}
+
+static EXAMPLE: &str = "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n";
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Entry>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, entries: &Self::Parsed) -> Answer {
+        count_valid(&RunPolicy::Count, entries).into()
+    }
+
+    fn part2(&self, entries: &Self::Parsed) -> Answer {
+        count_valid(&RunPolicy::Positions, entries).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(2).part2(1)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let entries = parse(EXAMPLE).unwrap();
+        assert_eq!(count_valid(&RunPolicy::Count, &entries), 2);
+        assert_eq!(count_valid(&RunPolicy::Positions, &entries), 1);
+    }
+
+    #[test]
+    fn test_policies() {
+        let check = |spec: &str, password: &str| parse_policy(spec, spec).unwrap().check(password);
+        assert_eq!(check("count 1-3 a", "abcde"), Ok(()));
+        assert_eq!(
+            check("count 1-3 b", "cdefg"),
+            Err("`b` appears 0 times, not 1-3".to_string())
+        );
+        assert_eq!(
+            check("positions 2-9 c", "ccccccccc"),
+            Err("`c` is at both 2 and 9".to_string())
+        );
+        assert_eq!(check("no-repeat", "abc"), Ok(()));
+        assert_eq!(
+            check("no-repeat", "abcb"),
+            Err("`b` appears 2 times".to_string())
+        );
+        assert_eq!(check("distinct 3", "abcb"), Ok(()));
+        assert!(check("distinct 4", "abcb").is_err());
+        assert_eq!(check("regex [a-c]+", "abcb"), Ok(()));
+        assert!(check("regex [a-c]+", "abcd").is_err());
+        // Leftmost-first alternation finds `a` first, but `ab` still matches
+        // the whole password.
+        assert_eq!(check("regex a|ab", "ab"), Ok(()));
+        assert_eq!(
+            check("regex a|ab", "abc"),
+            Err("doesn't match `a|ab`".to_string())
+        );
+
+        for spec in [
+            "count 1-3 a",
+            "positions 2-9 c",
+            "no-repeat",
+            "distinct 4",
+            "regex ^a",
+        ]
+        .iter()
+        {
+            assert_eq!(parse_policy(spec, spec).unwrap().to_string(), *spec);
+        }
+        let err = |spec: &str| parse_policy(spec, spec).err().unwrap().to_string();
+        assert_eq!(err("size 3"), "line 1, column 1: unknown policy `size`");
+        assert_eq!(err("distinct"), "line 1, column 9: missing count");
+        assert_eq!(
+            err("no-repeat 2"),
+            "line 1, column 11: `no-repeat` takes no arguments"
+        );
+        assert_eq!(
+            err("count 1-3"),
+            "line 1, column 7: expected `min-max letter`"
+        );
+        for bad in ["1-3 ab", "1-3 A", "1--3 a", "-3 a", "1-3  a", "+1-3 a"].iter() {
+            assert!(parse_numbers(bad, bad).is_err(), "{}", bad);
+        }
+        assert_eq!(parse_numbers("10-2 z", "10-2 z"), Ok((10, 2, 'z')));
+    }
+
+    #[test]
+    fn test_validate() {
+        let text = "1-3 a: abcde\n0-3 a: abc\n\n1-3 a abc\n2-9 c: ccccccccc\n";
+        let reports = validate(text, &RunPolicy::Positions);
+        assert_eq!(
+            report_text(&reports),
+            "line 1: valid (positions 1-3 a)
+line 2: error (positions 0-3 a): position 0 is outside the password's 1-3
+line 4: error: column 1: expected `min-max letter: password`
+line 5: invalid (positions 2-9 c): `c` is at both 2 and 9
+"
+        );
+        assert_eq!(
+            report_csv(&reports[2..]),
+            "line,status,policy,reason
+4,error,,column 1: expected `min-max letter: password`
+5,invalid,positions 2-9 c,`c` is at both 2 and 9
+"
+        );
+        let entries = parse("1-9 a: abc\n").unwrap();
+        assert_eq!(count_valid(&RunPolicy::Positions, &entries), 0);
+
+        // Whitespace-only lines are errors here just as they are to `parse`.
+        let text = "1-3 a: abcde\n  \n";
+        assert!(parse(text).is_err());
+        assert!(matches!(
+            validate(text, &RunPolicy::Count)[1].status,
+            Status::Error(_)
+        ));
+    }
+
+    #[test]
+    fn test_corpus() {
+        let cases = vec![
+            (CorpusPolicy::Count, RunPolicy::Count),
+            (CorpusPolicy::Positions, RunPolicy::Positions),
+        ];
+        for (policy, run) in cases {
+            let options = CorpusOptions {
+                policy,
+                alphabet: vec!['a', 'b', 'c'],
+                min_len: 2,
+                max_len: 8,
+                valid_ratio: 0.3,
+            };
+            let corpus = generate_corpus(&mut Rng::new(1), &options, 500);
+            for (line, valid) in corpus.iter() {
+                let (_, status) = check_entry(&run, &parse_line(line).unwrap());
+                assert_eq!(status == Status::Valid, *valid, "{}", line);
+            }
+            let valid = corpus.iter().filter(|(_, valid)| *valid).count();
+            assert!(100 < valid && valid < 200, "{} valid", valid);
+        }
+
+        let options = CorpusOptions {
+            alphabet: vec!['a', 'a'],
+            ..CorpusOptions::default()
+        };
+        assert!(options.problem().is_some());
+        assert_eq!(CorpusOptions::default().problem(), None);
+    }
+
+    #[test]
+    fn test_line_policies() {
+        let entries = parse("1-3 a: abcde\ndistinct 5: abcde\nno-repeat: aa\n").unwrap();
+        let run = RunPolicy::parse("regex b.*").unwrap();
+        let checks: Vec<_> = entries.iter().map(|e| check_entry(&run, e)).collect();
+        let invalid = Status::Invalid("doesn't match `b.*`".to_string());
+        assert_eq!(checks[0], ("regex b.*".to_string(), invalid));
+        assert_eq!(checks[1], ("distinct 5".to_string(), Status::Valid));
+        assert_eq!(checks[2].0, "no-repeat");
+        assert_eq!(count_valid(&RunPolicy::Count, &entries), 2);
+
+        let err = parse("1-3 a: abcde\nfoo: abc").err().unwrap();
+        assert_eq!(err.to_string(), "line 2, column 1: unknown policy `foo`");
+        let err = parse("1-3 a abcde").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected `min-max letter: password`"
+        );
+    }
+}
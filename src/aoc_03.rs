@@ -0,0 +1,389 @@
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// The map, with `true` where there's a tree. `Grid` stores cells in one
+/// flat vector and wraps coordinates itself, so each lookup along a slope is
+/// O(1) however far right the slope has gone.
+pub fn parse(file: &str) -> Result<Grid<bool>> {
+    Grid::parse(file, |c| match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })
+}
+
+/// A way down the slope: `right` columns across (negative for left) for
+/// every `down` rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slope {
+    pub right: i64,
+    pub down: usize,
+}
+
+impl Slope {
+    pub const fn new(right: i64, down: usize) -> Slope {
+        Slope { right, down }
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "right {}, down {}", self.right, self.down)
+    }
+}
+
+/// The squares a toboggan visits going down `slope` from the top left, with
+/// x not wrapped.
+pub fn slope_path(map: &Grid<bool>, slope: Slope) -> Vec<(i64, usize)> {
+    assert!(slope.down > 0, "slopes have to go down");
+    (0..map.height())
+        .step_by(slope.down)
+        .enumerate()
+        .map(|(i, y)| (i as i64 * slope.right, y))
+        .collect()
+}
+
+/// How many trees a toboggan hits going down `slope` from the top left.
+pub fn traverse(map: &Grid<bool>, slope: Slope) -> usize {
+    slope_path(map, slope)
+        .into_iter()
+        .filter(|&(x, y)| *map.get_wrapping(x, y as i64))
+        .count()
+}
+
+/// Draws the map the way the puzzle text does: repeated sideways as far as
+/// `path` goes, with the open squares on it marked `O` and the trees `X`.
+/// With `color`, the marks are colored for a terminal.
+pub fn render(map: &Grid<bool>, path: &[(i64, usize)], color: bool) -> String {
+    let w = map.width() as i64;
+    let xs = path.iter().map(|&(x, _)| x);
+    let first = xs.clone().min().unwrap_or(0).min(0).div_euclid(w) * w;
+    let last = (xs.max().unwrap_or(0).max(0).div_euclid(w) + 1) * w;
+    let visited: HashSet<(i64, usize)> = path.iter().copied().collect();
+
+    let mut out = String::new();
+    for y in 0..map.height() {
+        for x in first..last {
+            let tree = *map.get_wrapping(x, y as i64);
+            out += match (visited.contains(&(x, y)), tree, color) {
+                (false, false, _) => ".",
+                (false, true, _) => "#",
+                (true, false, false) => "O",
+                (true, true, false) => "X",
+                (true, false, true) => "\x1b[32mO\x1b[0m",
+                (true, true, true) => "\x1b[1;31mX\x1b[0m",
+            };
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// The slopes part 2 checks.
+pub static SLOPES: [Slope; 5] = [
+    Slope::new(1, 1),
+    Slope::new(3, 1),
+    Slope::new(5, 1),
+    Slope::new(7, 1),
+    Slope::new(1, 2),
+];
+
+/// Every slope with `right` and `down` in the given ranges, and the trees it
+/// hits, sorted from fewest trees to most.
+pub fn sweep(
+    map: &Grid<bool>,
+    right: RangeInclusive<i64>,
+    down: RangeInclusive<usize>,
+) -> Vec<(Slope, usize)> {
+    let mut results: Vec<(Slope, usize)> = down
+        .flat_map(|d| right.clone().map(move |r| Slope::new(r, d)))
+        .map(|slope| (slope, traverse(map, slope)))
+        .collect();
+    results.sort_by_key(|&(_, trees)| trees);
+    results
+}
+
+/// A path down the map and how many trees it hits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub trees: usize,
+    /// Every position visited, from the top row to the bottom one. `x`
+    /// isn't wrapped, so it shows how far the path strays left or right.
+    pub path: Vec<(i64, usize)>,
+}
+
+/// The route from any square on the top row to the bottom row that hits
+/// the fewest trees, moving only by `steps` of `(right, down)`. Squares are
+/// counted where the route stops, including its start. `None` if the steps
+/// can't reach the bottom row.
+///
+/// Each step costs 0 or 1, so a 0-1 BFS over the wrapped squares finds it
+/// in O(width * height * steps).
+pub fn least_trees(map: &Grid<bool>, steps: &[(i64, i64)]) -> Option<Route> {
+    let (width, height) = (map.width(), map.height());
+    if height == 0 {
+        return None;
+    }
+    let index = |x: usize, y: usize| y * width + x;
+    let mut cost = vec![usize::MAX; width * height];
+    // The square each one was reached from, and the step taken.
+    let mut came_from: Vec<Option<(usize, usize, i64)>> = vec![None; width * height];
+    let mut queue = VecDeque::new();
+    for x in 0..width {
+        cost[index(x, 0)] = map[(x, 0)] as usize;
+        queue.push_back((x, 0));
+    }
+
+    while let Some((x, y)) = queue.pop_front() {
+        if y == height - 1 {
+            continue;
+        }
+        let here = cost[index(x, y)];
+        for &(right, down) in steps.iter() {
+            let ny = y as i64 + down;
+            if ny < 0 || ny >= height as i64 {
+                continue;
+            }
+            let (nx, ny) = (
+                (x as i64 + right).rem_euclid(width as i64) as usize,
+                ny as usize,
+            );
+            let tree = map[(nx, ny)];
+            let next = here + tree as usize;
+            if next < cost[index(nx, ny)] {
+                cost[index(nx, ny)] = next;
+                came_from[index(nx, ny)] = Some((x, y, right));
+                if tree {
+                    queue.push_back((nx, ny));
+                } else {
+                    queue.push_front((nx, ny));
+                }
+            }
+        }
+    }
+
+    let end = (0..width).min_by_key(|&x| cost[index(x, height - 1)])?;
+    let trees = cost[index(end, height - 1)];
+    if trees == usize::MAX {
+        return None;
+    }
+    // Walk back to the start, then unwrap x by replaying the steps forward.
+    let (mut x, mut y) = (end, height - 1);
+    let (mut rights, mut rows) = (Vec::new(), vec![y]);
+    while let Some((from_x, from_y, right)) = came_from[index(x, y)] {
+        rights.push(right);
+        rows.push(from_y);
+        x = from_x;
+        y = from_y;
+    }
+    rows.reverse();
+    let mut x = x as i64;
+    let mut path = vec![(x, rows[0])];
+    for (right, &y) in rights.iter().rev().zip(rows[1..].iter()) {
+        x += right;
+        path.push((x, y));
+    }
+    Some(Route { trees, path })
+}
+
+/// A map `size` rows tall and 31 wide, like the real inputs, with trees
+/// counted along each slope as the rows are made.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut trees = [0; 5];
+    for y in 0..size {
+        let row: Vec<bool> = (0..31).map(|_| rng.chance(0.25)).collect();
+        for (count, slope) in trees.iter_mut().zip(SLOPES.iter()) {
+            if y % slope.down == 0 && row[(y / slope.down) * slope.right as usize % 31] {
+                *count += 1;
+            }
+        }
+        input.extend(row.iter().map(|&tree| if tree { '#' } else { '.' }));
+        input.push('\n');
+    }
+    Generated::new(input)
+        .part1(trees[1])
+        .part2(trees.iter().product::<usize>())
+}
+
+static EXAMPLE: &str = r#"..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+"#;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Grid<bool>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, map: &Self::Parsed) -> Answer {
+        traverse(map, Slope::new(3, 1)).into()
+    }
+
+    fn part2(&self, map: &Self::Parsed) -> Answer {
+        SLOPES
+            .iter()
+            .map(|&slope| traverse(map, slope))
+            .product::<usize>()
+            .into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(7).part2(336)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let map = parse(EXAMPLE).unwrap();
+        assert_eq!(map.width(), 11);
+        assert_eq!(map.height(), 11);
+    }
+
+    #[test]
+    fn test_traverse() {
+        let map = parse(EXAMPLE).unwrap();
+        let tree_count = traverse(&map, Slope::new(3, 1));
+        assert_eq!(tree_count, 7);
+        // Going left mirrors the map, so it's the same as going right on the
+        // mirrored rows.
+        let mirrored: String = EXAMPLE
+            .lines()
+            .map(|l| l[..1].to_string() + &l[1..].chars().rev().collect::<String>() + "\n")
+            .collect();
+        let mirrored = parse(&mirrored).unwrap();
+        for &slope in SLOPES.iter() {
+            let left = Slope::new(-slope.right, slope.down);
+            assert_eq!(traverse(&mirrored, left), traverse(&map, slope));
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let map = parse(EXAMPLE).unwrap();
+        let path = slope_path(&map, Slope::new(3, 1));
+        let rendered = render(&map, &path, false);
+        let rows: Vec<&str> = rendered.lines().collect();
+        assert_eq!(rows.len(), 11);
+        assert!(rows.iter().all(|row| row.len() == 33));
+        assert_eq!(
+            rows[0],
+            "O.##.......".to_string() + &"..##.......".repeat(2)
+        );
+        assert_eq!(
+            rows[2],
+            ".#....X..#.".to_string() + &".#....#..#.".repeat(2)
+        );
+        assert_eq!(rows[10], ".#..#...#.#.#..#...#.#.#..#...X.#");
+        assert_eq!(
+            rendered.matches('X').count(),
+            traverse(&map, Slope::new(3, 1))
+        );
+
+        // Going left adds copies of the map on the left instead.
+        let path = slope_path(&map, Slope::new(-1, 2));
+        let rendered = render(&map, &path, true);
+        let rows: Vec<&str> = rendered.lines().collect();
+        assert_eq!(&rows[0][..11], "..##.......");
+        assert!(rows[0][11..].starts_with("\x1b[32mO\x1b[0m.##"));
+        assert_eq!(rows[1], "#...#...#..".repeat(2));
+    }
+
+    #[test]
+    fn test_part2() {
+        let map = parse(EXAMPLE).unwrap();
+        let trees: Vec<usize> = SLOPES.iter().map(|&slope| traverse(&map, slope)).collect();
+        assert_eq!(trees, vec![2, 7, 3, 4, 2]);
+        assert_eq!(trees.iter().product::<usize>(), 336);
+    }
+
+    /// Row-by-row minimum for steps that all go down one row.
+    fn least_trees_by_rows(map: &Grid<bool>, steps: &[(i64, i64)]) -> usize {
+        let w = map.width() as i64;
+        let mut best: Vec<usize> = (0..w).map(|x| *map.get_wrapping(x, 0) as usize).collect();
+        for y in 1..map.height() as i64 {
+            best = (0..w)
+                .map(|x| {
+                    let from = steps
+                        .iter()
+                        .map(|&(r, _)| best[(x - r).rem_euclid(w) as usize]);
+                    from.min().unwrap() + *map.get_wrapping(x, y) as usize
+                })
+                .collect();
+        }
+        *best.iter().min().unwrap()
+    }
+
+    #[test]
+    fn test_least_trees() {
+        let map = parse(EXAMPLE).unwrap();
+        for steps in [
+            vec![(0, 1)],
+            vec![(0, 1), (-1, 1), (1, 1)],
+            vec![(3, 1), (-2, 1)],
+        ]
+        .iter()
+        {
+            let route = least_trees(&map, steps).unwrap();
+            assert_eq!(route.trees, least_trees_by_rows(&map, steps));
+            assert_eq!(route.path.len(), map.height());
+            let hit = route
+                .path
+                .iter()
+                .filter(|&&(x, y)| *map.get_wrapping(x, y as i64));
+            assert_eq!(hit.count(), route.trees);
+            for w in route.path.windows(2) {
+                let step = (w[1].0 - w[0].0, (w[1].1 - w[0].1) as i64);
+                assert!(steps.contains(&step), "{:?}", step);
+            }
+        }
+
+        // Sideways steps can dodge trees that straight down can't.
+        let map = parse(".##\n..#\n#.#\n").unwrap();
+        assert_eq!(least_trees(&map, &[(0, 1)]).unwrap().trees, 1);
+        let route = least_trees(&map, &[(0, 1), (1, 0)]).unwrap();
+        assert_eq!(route.trees, 0);
+        assert_eq!(route.path.first().unwrap().1, 0);
+        assert_eq!(route.path.last().unwrap().1, 2);
+
+        // Two rows at a time never lands on the last of an even number.
+        let map = parse("..\n..\n").unwrap();
+        assert_eq!(least_trees(&map, &[(0, 2)]), None);
+    }
+
+    #[test]
+    fn test_sweep() {
+        let map = parse(EXAMPLE).unwrap();
+        let results = sweep(&map, -3..=3, 1..=2);
+        assert_eq!(results.len(), 14);
+        assert!(results.windows(2).all(|w| w[0].1 <= w[1].1));
+        let (best, trees) = results[0];
+        assert_eq!(trees, traverse(&map, best));
+        assert!(SLOPES.iter().all(|&s| traverse(&map, s) >= trees));
+    }
+}
@@ -0,0 +1,880 @@
+use crate::error::{parse_num, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use crate::{csv, json};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::sync::OnceLock;
+
+/// The keys the puzzle defines. Passports may have others, which are kept as
+/// extras.
+pub static KEYS: [&str; 8] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+
+/// A passport as written, before any of its fields are checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RawPassport {
+    /// The line the passport starts on.
+    pub line: usize,
+    /// Every `key:value` in order, including repeated keys and keys that
+    /// aren't in `KEYS`.
+    pub fields: Vec<(String, String)>,
+}
+
+impl RawPassport {
+    /// The value for a key like `byr`, if the passport has one. A repeated
+    /// key gives its last value.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .rev()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// The fields whose keys aren't in `KEYS`.
+    pub fn extras(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields
+            .iter()
+            .filter(|(k, _)| !KEYS.contains(&k.as_str()))
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+/// The fields on one line, as `parse` reads them.
+impl fmt::Display for RawPassport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}:{}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes passports back out in the puzzle's format, which `parse` reads back
+/// to the same fields.
+pub fn to_text(passports: &[RawPassport]) -> String {
+    passports
+        .iter()
+        .map(|pp| format!("{}\n", pp))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Reads passports: `key:value` fields split by any whitespace, with a blank
+/// line (which may still hold spaces or a `\r`) between passports. Repeated
+/// keys are returned as warnings pointing at the repeat.
+pub fn parse_with_warnings(file: &str) -> Result<(Vec<RawPassport>, Vec<AocError>)> {
+    let mut passports = Vec::new();
+    let mut warnings = Vec::new();
+    let mut current: Option<RawPassport> = None;
+    for (i, line) in file.split('\n').enumerate() {
+        if line.trim().is_empty() {
+            passports.extend(current.take());
+            continue;
+        }
+        let pp = current.get_or_insert_with(|| RawPassport {
+            line: i + 1,
+            fields: Vec::new(),
+        });
+        for field in line.split_whitespace() {
+            let (key, value) = field.split_once(':').ok_or_else(|| {
+                AocError::at(
+                    file,
+                    &field[field.len()..],
+                    ErrorKind::Missing("`:` after key"),
+                )
+            })?;
+            if key.is_empty() {
+                return Err(AocError::at(file, field, ErrorKind::Missing("key")));
+            }
+            if pp.get(key).is_some() {
+                warnings.push(AocError::at(
+                    file,
+                    key,
+                    ErrorKind::Invalid(format!("duplicate key `{}`, using the last value", key)),
+                ));
+            }
+            pp.fields.push((key.to_string(), value.to_string()));
+        }
+    }
+    passports.extend(current);
+    Ok((passports, warnings))
+}
+
+/// `parse_with_warnings`, logging the warnings.
+pub fn parse(file: &str) -> Result<Vec<RawPassport>> {
+    let (passports, warnings) = parse_with_warnings(file)?;
+    for warning in warnings.iter() {
+        info!("{}", warning);
+    }
+    Ok(passports)
+}
+
+/// What the value of a passport field has to look like.
+#[derive(Debug, Clone)]
+pub enum Rule {
+    Any,
+    /// A year in this range.
+    Year(RangeInclusive<u32>),
+    /// A number followed by one of the units, in that unit's range.
+    Number(Vec<(String, RangeInclusive<u32>)>),
+    /// The whole value matches `pattern`.
+    Regex {
+        pattern: String,
+        re: Regex,
+    },
+    Enum(Vec<String>),
+    /// Exactly this many digits.
+    Digits(usize),
+}
+
+fn all_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Why a value fails its rule: a short `reason` that failures are grouped
+/// by, and the details for this value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub reason: &'static str,
+    pub detail: String,
+}
+
+fn fail(reason: &'static str, detail: String) -> std::result::Result<(), Failure> {
+    Err(Failure { reason, detail })
+}
+
+/// Checks that `digits`, the number in `value`, is in `range`.
+fn check_range(
+    value: &str,
+    digits: &str,
+    range: &RangeInclusive<u32>,
+) -> std::result::Result<(), Failure> {
+    match digits.parse::<u32>() {
+        Ok(x) if range.contains(&x) => Ok(()),
+        _ => fail(
+            "out of range",
+            format!("{} out of range {}–{}", value, range.start(), range.end()),
+        ),
+    }
+}
+
+impl Rule {
+    /// Checks `value`, explaining why it fails if it does.
+    pub fn check(&self, value: &str) -> std::result::Result<(), Failure> {
+        match self {
+            Rule::Any => Ok(()),
+            Rule::Year(range) if all_digits(value) => check_range(value, value, range),
+            Rule::Year(_) => fail("not a year", format!("{} is not a year", value)),
+            Rule::Number(units) => {
+                let split = value
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(value.len());
+                let (digits, unit) = value.split_at(split);
+                let names: Vec<&str> = units.iter().map(|(u, _)| u.as_str()).collect();
+                let names = names.join(" or ");
+                match units.iter().find(|(u, _)| u == unit) {
+                    _ if digits.is_empty() => {
+                        fail("not a number", format!("{} is not a number", value))
+                    }
+                    Some((_, range)) => check_range(value, digits, range),
+                    None if unit.is_empty() => fail(
+                        "no unit",
+                        format!("{} has no unit, expected {}", value, names),
+                    ),
+                    None => fail(
+                        "wrong unit",
+                        format!("{} has unit `{}`, expected {}", value, unit, names),
+                    ),
+                }
+            }
+            Rule::Regex { pattern, re } if !re.is_match(value) => fail(
+                "doesn't match",
+                format!("{} doesn't match `{}`", value, pattern),
+            ),
+            Rule::Regex { .. } => Ok(()),
+            Rule::Enum(values) if !values.iter().any(|v| v == value) => fail(
+                "not allowed",
+                format!("{} is not one of {}", value, values.join(", ")),
+            ),
+            Rule::Enum(_) => Ok(()),
+            Rule::Digits(_) if !all_digits(value) => {
+                fail("not digits", format!("{} is not all digits", value))
+            }
+            Rule::Digits(n) if value.len() != *n => fail(
+                "wrong length",
+                format!("{} digits, expected {}", value.len(), n),
+            ),
+            Rule::Digits(_) => Ok(()),
+        }
+    }
+}
+
+/// A field of a passport that breaks the schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub key: String,
+    pub failure: Failure,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.failure.detail)
+    }
+}
+
+/// One field of a `Schema`.
+#[derive(Debug, Clone)]
+pub struct FieldSpec {
+    pub key: String,
+    pub required: bool,
+    pub rule: Rule,
+}
+
+/// The fields passports have and the rules their values follow. The default
+/// is the puzzle's, read from `passport.schema`.
+#[derive(Debug, Clone)]
+pub struct Schema {
+    pub fields: Vec<FieldSpec>,
+}
+
+static DEFAULT_SCHEMA: &str = include_str!("../passport.schema");
+
+/// Splits the first word off `s`, returning it and the rest.
+fn split_word(s: &str) -> (&str, &str) {
+    match s.find(char::is_whitespace) {
+        Some(i) => (&s[..i], s[i..].trim_start()),
+        None => (s, &s[s.len()..]),
+    }
+}
+
+/// Reads `min-max`, a slice of `src`.
+fn parse_span(src: &str, text: &str) -> Result<RangeInclusive<u32>> {
+    let expected = || {
+        AocError::at(
+            src,
+            text,
+            ErrorKind::Invalid("expected `min-max`".to_string()),
+        )
+    };
+    let (min, max) = text.split_once('-').ok_or_else(expected)?;
+    let (min, max) = (parse_num(src, min)?, parse_num(src, max)?);
+    if min > max {
+        return Err(expected());
+    }
+    Ok(min..=max)
+}
+
+/// Reads a rule, a slice of `src`, as described in `passport.schema`.
+fn parse_rule(src: &str, spec: &str) -> Result<Rule> {
+    let (name, args) = split_word(spec);
+    match name {
+        "" => Ok(Rule::Any),
+        "year" => Ok(Rule::Year(parse_span(src, args)?)),
+        "number" => {
+            let mut units = Vec::new();
+            for unit in args.split(',') {
+                let (unit, span) = split_word(unit.trim());
+                if unit.is_empty() {
+                    return Err(AocError::at(src, unit, ErrorKind::Missing("unit")));
+                }
+                units.push((unit.to_string(), parse_span(src, span)?));
+            }
+            Ok(Rule::Number(units))
+        }
+        "regex" => {
+            let re = Regex::new(&format!("^(?:{})$", args))
+                .map_err(|e| AocError::at(src, args, ErrorKind::Invalid(e.to_string())))?;
+            Ok(Rule::Regex {
+                pattern: args.to_string(),
+                re,
+            })
+        }
+        "enum" if args.is_empty() => Err(AocError::at(src, args, ErrorKind::Missing("values"))),
+        "enum" => Ok(Rule::Enum(
+            args.split_whitespace().map(str::to_string).collect(),
+        )),
+        "digits" => Ok(Rule::Digits(parse_num(src, args)?)),
+        _ => Err(AocError::at(
+            src,
+            name,
+            ErrorKind::Unknown {
+                what: "rule",
+                token: name.to_string(),
+            },
+        )),
+    }
+}
+
+/// Reads one `key required|optional [rule]` line.
+fn parse_field(line: &str) -> Result<FieldSpec> {
+    let (key, rest) = split_word(line);
+    let (required, rule) = split_word(rest);
+    let required = match required {
+        "required" => true,
+        "optional" => false,
+        _ => {
+            return Err(AocError::at(
+                line,
+                required,
+                ErrorKind::Invalid("expected `required` or `optional`".to_string()),
+            ))
+        }
+    };
+    Ok(FieldSpec {
+        key: key.to_string(),
+        required,
+        rule: parse_rule(line, rule)?,
+    })
+}
+
+impl Schema {
+    /// Reads a schema: a field per line, skipping blank lines and `#`
+    /// comments.
+    pub fn parse(text: &str) -> Result<Schema> {
+        let mut fields: Vec<FieldSpec> = Vec::new();
+        for line in text.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let field = parse_field(trimmed).map_err(|e| e.within(text, trimmed))?;
+            if fields.iter().any(|f| f.key == field.key) {
+                return Err(AocError::at(
+                    text,
+                    &trimmed[..field.key.len()],
+                    ErrorKind::Invalid(format!("`{}` is defined twice", field.key)),
+                ));
+            }
+            fields.push(field);
+        }
+        Ok(Schema { fields })
+    }
+
+    /// The puzzle's schema, parsed from `passport.schema` the first time
+    /// it's needed.
+    pub fn builtin() -> &'static Schema {
+        static BUILTIN: OnceLock<Schema> = OnceLock::new();
+        BUILTIN.get_or_init(|| Schema::parse(DEFAULT_SCHEMA).expect("passport.schema should parse"))
+    }
+
+    pub fn field(&self, key: &str) -> Option<&FieldSpec> {
+        self.fields.iter().find(|f| f.key == key)
+    }
+
+    /// Whether `pp` has every required field, as part 1 asks.
+    pub fn complete(&self, pp: &RawPassport) -> bool {
+        self.fields
+            .iter()
+            .all(|f| !f.required || pp.get(&f.key).is_some())
+    }
+
+    /// Every required field `pp` is missing and every field it has that
+    /// breaks its rule, in schema order.
+    pub fn violations(&self, pp: &RawPassport) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for f in self.fields.iter() {
+            let failure = match pp.get(&f.key) {
+                Some(value) => f.rule.check(value).err(),
+                None if f.required => Some(Failure {
+                    reason: "missing",
+                    detail: "missing".to_string(),
+                }),
+                None => None,
+            };
+            if let Some(failure) = failure {
+                violations.push(Violation {
+                    key: f.key.clone(),
+                    failure,
+                });
+            }
+        }
+        violations
+    }
+
+    /// Whether `pp` is complete and every field it has follows its rule, as
+    /// part 2 asks.
+    pub fn valid(&self, pp: &RawPassport) -> bool {
+        self.fields.iter().all(|f| match pp.get(&f.key) {
+            Some(value) => f.rule.check(value).is_ok(),
+            None => !f.required,
+        })
+    }
+}
+
+impl Default for Schema {
+    fn default() -> Schema {
+        Schema::builtin().clone()
+    }
+}
+
+/// The violations of one passport in a batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassportReport {
+    /// 1-based, in input order.
+    pub index: usize,
+    /// The line the passport starts on.
+    pub line: usize,
+    pub violations: Vec<Violation>,
+}
+
+/// Checks every passport against `schema`.
+pub fn validate(schema: &Schema, passports: &[RawPassport]) -> Vec<PassportReport> {
+    passports
+        .iter()
+        .enumerate()
+        .map(|(i, pp)| PassportReport {
+            index: i + 1,
+            line: pp.line,
+            violations: schema.violations(pp),
+        })
+        .collect()
+}
+
+/// How often each field failed for each reason, e.g. `hgt out of range`,
+/// most common first.
+pub fn summarize(reports: &[PassportReport]) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for v in reports.iter().flat_map(|r| r.violations.iter()) {
+        *counts
+            .entry(format!("{} {}", v.key, v.failure.reason))
+            .or_default() += 1;
+    }
+    let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+/// A line per passport, followed by one per violation it has, then the
+/// summary.
+pub fn report_text(reports: &[PassportReport]) -> String {
+    let mut text = String::new();
+    for r in reports.iter() {
+        text += &format!("passport {} (line {}): ", r.index, r.line);
+        match r.violations.len() {
+            0 => text += "valid\n",
+            1 => text += "1 violation\n",
+            n => text += &format!("{} violations\n", n),
+        }
+        for v in r.violations.iter() {
+            text += &format!("    {}\n", v);
+        }
+    }
+    let invalid = reports.iter().filter(|r| !r.violations.is_empty()).count();
+    text += &format!("\n{} of {} passports invalid\n", invalid, reports.len());
+    for (reason, count) in summarize(reports) {
+        text += &format!("{:>6}  {}\n", count, reason);
+    }
+    text
+}
+
+/// The columns an export adds around the fields.
+static EXPORT_COLUMNS: [&str; 3] = ["line", "valid_part1", "valid_part2"];
+
+/// The fields an export has columns for, as `(column, key)`: `KEYS`, then
+/// every extra key in the order it first shows up. An extra key that would
+/// share a column name, like `line`, gets `extra_` put in front until it
+/// doesn't.
+fn export_keys(passports: &[RawPassport]) -> Vec<(String, &str)> {
+    let mut keys: Vec<(String, &str)> = KEYS.iter().map(|&k| (k.to_string(), k)).collect();
+    for (key, _) in passports.iter().flat_map(|pp| pp.extras()) {
+        if keys.iter().any(|&(_, k)| k == key) {
+            continue;
+        }
+        let mut column = key.to_string();
+        while EXPORT_COLUMNS.contains(&column.as_str()) || keys.iter().any(|(c, _)| *c == column) {
+            column.insert_str(0, "extra_");
+        }
+        keys.push((column, key));
+    }
+    keys
+}
+
+/// The passports as CSV: the starting line, a column per field (empty where
+/// a passport doesn't have it), and whether each passport is valid under
+/// part 1's rules and `schema`'s.
+pub fn export_csv(schema: &Schema, passports: &[RawPassport]) -> String {
+    let keys = export_keys(passports);
+    let mut header = vec![EXPORT_COLUMNS[0]];
+    header.extend(keys.iter().map(|(column, _)| column.as_str()));
+    header.extend(EXPORT_COLUMNS[1..].iter());
+    let mut text = csv::row(&header);
+    for pp in passports.iter() {
+        let mut row = vec![pp.line.to_string()];
+        row.extend(
+            keys.iter()
+                .map(|(_, k)| pp.get(k).unwrap_or("").to_string()),
+        );
+        row.push(schema.complete(pp).to_string());
+        row.push(schema.valid(pp).to_string());
+        text += &csv::row(&row);
+    }
+    text
+}
+
+/// The same records as `export_csv`, as a JSON array with an object per
+/// passport on each line, and `null` for missing fields.
+pub fn export_json(schema: &Schema, passports: &[RawPassport]) -> String {
+    let keys = export_keys(passports);
+    let objects: Vec<String> = passports
+        .iter()
+        .map(|pp| {
+            let mut object = json::Object::new().field(EXPORT_COLUMNS[0], pp.line.to_string());
+            for (column, key) in keys.iter() {
+                let value = match pp.get(key) {
+                    Some(value) => json::string(value),
+                    None => "null".to_string(),
+                };
+                object = object.field(column, value);
+            }
+            object
+                .field(EXPORT_COLUMNS[1], schema.complete(pp).to_string())
+                .field(EXPORT_COLUMNS[2], schema.valid(pp).to_string())
+                .to_string()
+        })
+        .collect();
+    if objects.is_empty() {
+        return "[]\n".to_string();
+    }
+    format!("[\n{}\n]\n", objects.join(",\n"))
+}
+
+static EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+/// A value for `key` that passes or fails the part 2 rules.
+fn field_value(rng: &mut Rng, key: &str, valid: bool) -> String {
+    let year = |rng: &mut Rng, lo: i64, hi: i64| {
+        if valid {
+            rng.range(lo, hi)
+        } else if rng.chance(0.5) {
+            rng.range(lo - 20, lo - 1)
+        } else {
+            rng.range(hi + 1, hi + 20)
+        }
+        .to_string()
+    };
+    match (key, valid) {
+        ("byr", _) => year(rng, 1920, 2002),
+        ("iyr", _) => year(rng, 2010, 2020),
+        ("eyr", _) => year(rng, 2020, 2030),
+        ("hgt", true) if rng.chance(0.5) => format!("{}cm", rng.range(150, 193)),
+        ("hgt", true) => format!("{}in", rng.range(59, 76)),
+        ("hgt", false) => match rng.index(3) {
+            0 => format!("{}cm", rng.range(100, 149)),
+            1 => format!("{}in", rng.range(77, 99)),
+            _ => rng.range(59, 193).to_string(),
+        },
+        ("hcl", true) => format!("#{}", rng.string("0123456789abcdef", 6)),
+        ("hcl", false) => rng.string("0123456789abcdef", 6),
+        ("ecl", true) => rng.choose(&EYE_COLORS).to_string(),
+        ("ecl", false) => rng.string("xyz", 3),
+        ("pid", true) => rng.string("0123456789", 9),
+        ("pid", false) => {
+            let len = if rng.chance(0.5) { 8 } else { 10 };
+            rng.string("0123456789", len)
+        }
+        _ => rng.range(1, 999).to_string(),
+    }
+}
+
+/// `size` passports, each either valid, missing a required field, or with
+/// one required field out of range.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let required = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+    let mut passports = Vec::new();
+    let (mut valid1, mut valid2) = (0, 0);
+    for _ in 0..size {
+        let mut keys = required.to_vec();
+        if rng.chance(0.5) {
+            keys.push("cid");
+        }
+        let mut invalid = None;
+        match rng.index(3) {
+            0 => {
+                keys.remove(rng.index(required.len()));
+            }
+            1 => {
+                invalid = Some(*rng.choose(&required));
+                valid1 += 1;
+            }
+            _ => {
+                valid1 += 1;
+                valid2 += 1;
+            }
+        }
+        rng.shuffle(&mut keys);
+        let mut passport = String::new();
+        for (i, key) in keys.iter().enumerate() {
+            if i > 0 {
+                passport.push(if rng.chance(0.3) { '\n' } else { ' ' });
+            }
+            let value = field_value(rng, key, invalid != Some(*key));
+            passport += &format!("{}:{}", key, value);
+        }
+        passports.push(passport);
+    }
+    Generated::new(passports.join("\n\n") + "\n")
+        .part1(valid1)
+        .part2(valid2)
+}
+
+static EXAMPLE: &str = r#"ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in
+"#;
+
+static EXAMPLE_INVALID: &str = r#"eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007
+"#;
+
+static EXAMPLE_VALID: &str = r#"pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719
+"#;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Vec<RawPassport>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, pps: &Self::Parsed) -> Answer {
+        let schema = Schema::builtin();
+        pps.iter().filter(|x| schema.complete(x)).count().into()
+    }
+
+    fn part2(&self, pps: &Self::Parsed) -> Answer {
+        let schema = Schema::builtin();
+        pps.iter().filter(|x| schema.valid(x)).count().into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(EXAMPLE).part1(2),
+            Example::new(EXAMPLE_INVALID).part1(4).part2(0),
+            Example::new(EXAMPLE_VALID).part1(4).part2(4),
+        ]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let _pps = parse(EXAMPLE).unwrap();
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("byr:1937\niyr:2017 foo").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 13));
+        assert_eq!(err.to_string(), "line 2, column 13: missing `:` after key");
+        let err = parse("byr:1937 :2017").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 10: missing key");
+    }
+
+    #[test]
+    fn test_tokenizer() {
+        let text = "byr:1\tiyr:2\r\n  \r\n\r\nfoo:x byr:3\r\nbyr:4\r\n";
+        let (pps, warnings) = parse_with_warnings(text).unwrap();
+        assert_eq!(pps.len(), 2);
+        assert_eq!(pps[0].fields.len(), 2);
+        assert_eq!((pps[1].line, pps[1].get("byr")), (4, Some("4")));
+        assert_eq!(pps[1].extras().collect::<Vec<_>>(), vec![("foo", "x")]);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].to_string(),
+            "line 5, column 1: duplicate key `byr`, using the last value"
+        );
+    }
+
+    #[test]
+    fn test_to_text() {
+        let pps = parse(EXAMPLE_VALID).unwrap();
+        let text = to_text(&pps);
+        assert!(text.starts_with(
+            "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f\n\neyr:2029"
+        ));
+        let fields =
+            |pps: Vec<RawPassport>| pps.into_iter().map(|pp| pp.fields).collect::<Vec<_>>();
+        assert_eq!(fields(parse(&text).unwrap()), fields(pps));
+    }
+
+    #[test]
+    fn test_par1() {
+        let pps = parse(EXAMPLE).unwrap();
+        let schema = Schema::default();
+        let valid_count = pps.iter().filter(|x| schema.complete(x)).count();
+        assert_eq!(valid_count, 2);
+    }
+
+    #[test]
+    fn test_rules() {
+        let schema = Schema::default();
+        let check = |key: &str, value: &str| {
+            let rule = &schema.field(key).unwrap().rule;
+            rule.check(value).map_err(|f| f.detail)
+        };
+        assert_eq!(check("byr", "2002"), Ok(()));
+        assert_eq!(
+            check("byr", "2003"),
+            Err("2003 out of range 1920–2002".to_string())
+        );
+        assert_eq!(check("hgt", "60in"), Ok(()));
+        assert_eq!(check("hgt", "190cm"), Ok(()));
+        assert_eq!(
+            check("hgt", "190in"),
+            Err("190in out of range 59–76".to_string())
+        );
+        assert_eq!(
+            check("hgt", "190"),
+            Err("190 has no unit, expected cm or in".to_string())
+        );
+        assert_eq!(check("hcl", "#123abc"), Ok(()));
+        assert!(check("hcl", "#123abz").is_err());
+        assert!(check("hcl", "123abc").is_err());
+        assert_eq!(check("ecl", "brn"), Ok(()));
+        assert!(check("ecl", "wat").is_err());
+        assert_eq!(check("pid", "000000001"), Ok(()));
+        assert_eq!(
+            check("pid", "01234567"),
+            Err("8 digits, expected 9".to_string())
+        );
+        assert_eq!(check("cid", "anything"), Ok(()));
+    }
+
+    #[test]
+    fn test_report() {
+        let text = "byr:1937 iyr:2017 eyr:2020\nhgt:190in hcl:#fffffd ecl:gry pid:01234567\n\n\n"
+            .to_string()
+            + EXAMPLE_VALID;
+        let passports = parse(&text).unwrap();
+        let reports = validate(&Schema::default(), &passports);
+        assert_eq!(reports.len(), 5);
+        assert_eq!((reports[1].index, reports[1].line), (2, 5));
+        assert!(reports[1..].iter().all(|r| r.violations.is_empty()));
+        let violations: Vec<String> = reports[0]
+            .violations
+            .iter()
+            .map(|v| v.to_string())
+            .collect();
+        assert_eq!(
+            violations,
+            vec!["hgt: 190in out of range 59–76", "pid: 8 digits, expected 9"]
+        );
+
+        let schema = Schema::builtin();
+        let generated = generate(&mut Rng::new(4), 300);
+        for pp in parse(&generated.input).unwrap().iter() {
+            assert_eq!(schema.valid(pp), schema.violations(pp).is_empty(), "{}", pp);
+        }
+
+        let reports = validate(&Schema::default(), &parse(EXAMPLE_INVALID).unwrap());
+        let summary = summarize(&reports);
+        assert_eq!(summary[0], ("eyr out of range".to_string(), 3));
+        assert_eq!(summary.iter().map(|(_, n)| n).sum::<usize>(), 12);
+        let text = report_text(&reports);
+        assert!(text.starts_with("passport 1 (line 1): 3 violations\n    eyr: 1972 out of range 2020–2030\n    hgt: 170 has no unit"));
+        assert!(text.contains("\n4 of 4 passports invalid\n     3  eyr out of range\n"));
+    }
+
+    #[test]
+    fn test_export() {
+        let pps = parse("byr:1937 cid:1 zz:a,b\n\nhgt:59in\nqq:\"x\"\n").unwrap();
+        let schema = Schema::default();
+        assert_eq!(
+            export_csv(&schema, &pps),
+            "line,byr,iyr,eyr,hgt,hcl,ecl,pid,cid,zz,qq,valid_part1,valid_part2\n\
+             1,1937,,,,,,,1,\"a,b\",,false,false\n\
+             3,,,,59in,,,,,,\"\"\"x\"\"\",false,false\n"
+        );
+        let json = export_json(&schema, &pps);
+        assert_eq!(json.lines().count(), 4);
+        assert!(json.starts_with("[\n{\"line\":1,\"byr\":\"1937\",\"iyr\":null,"));
+        assert!(
+            json.contains("\"qq\":\"\\\"x\\\"\",\"valid_part1\":false,\"valid_part2\":false}\n]")
+        );
+
+        let pps = parse(EXAMPLE_VALID).unwrap();
+        let csv = export_csv(&schema, &pps);
+        assert_eq!(csv.lines().count(), 5);
+        assert!(csv.lines().skip(1).all(|l| l.ends_with(",true,true")));
+        assert_eq!(export_json(&schema, &[]), "[]\n");
+
+        // Extra keys named like the added columns, or like each other once
+        // renamed, get columns of their own.
+        let pps = parse("byr:1 line:5 valid_part1:x extra_line:y\n").unwrap();
+        let csv = export_csv(&schema, &pps);
+        assert!(csv.starts_with(
+            "line,byr,iyr,eyr,hgt,hcl,ecl,pid,cid,extra_line,extra_valid_part1,extra_extra_line,valid_part1,valid_part2\n\
+             1,1,,,,,,,,5,x,y,false,false\n"
+        ));
+        let json = export_json(&schema, &pps);
+        assert!(json.contains(
+            r#""extra_line":"5","extra_valid_part1":"x","extra_extra_line":"y","valid_part1":false"#
+        ));
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = Schema::parse("# comment\n\nbyr required year 1-2\ncid optional\n").unwrap();
+        assert_eq!(schema.fields.len(), 2);
+        assert!(schema.fields[0].required && !schema.fields[1].required);
+
+        let err = Schema::parse("byr required\nhgt required number cm 5").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 24: expected `min-max`");
+        let err = Schema::parse("byr maybe").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 5: expected `required` or `optional`"
+        );
+        let err = Schema::parse("byr required colour red").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 14: unknown rule `colour`");
+        let err = Schema::parse("byr optional\nbyr required").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: `byr` is defined twice");
+    }
+}
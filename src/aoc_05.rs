@@ -0,0 +1,233 @@
+use crate::error::{parse_lines, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+
+static SEAT_LENGTH: &str = "expected 10 characters, e.g. `FBFBBFFRLR`";
+
+fn unexpected(line: &str, i: usize, c: char) -> AocError {
+    AocError::at(line, &line[i..], ErrorKind::UnexpectedChar(c))
+}
+
+// Original implementation, probably faster, but harder to write
+fn _parse_line(line: &str) -> Result<(u32, u32, u32)> {
+    if line.len() != 10 {
+        Err(AocError::at(
+            line,
+            line,
+            ErrorKind::Invalid(SEAT_LENGTH.to_string()),
+        ))
+    } else {
+        let mut itr = line.char_indices();
+        let row = {
+            let mut row_low_bound: u32 = 0;
+            let mut row_range: u32 = 64;
+            for _i in 0..7 {
+                match itr.next().unwrap() {
+                    (_, 'F') => (),
+                    (_, 'B') => row_low_bound += row_range,
+                    (i, c) => return Err(unexpected(line, i, c)),
+                };
+                row_range /= 2;
+            }
+            row_low_bound
+        };
+        let col = {
+            let mut col_low_bound: u32 = 0;
+            let mut col_range: u32 = 4;
+            for _i in 0..3 {
+                match itr.next().unwrap() {
+                    (_, 'L') => (),
+                    (_, 'R') => col_low_bound += col_range,
+                    (i, c) => return Err(unexpected(line, i, c)),
+                };
+                col_range /= 2;
+            }
+            col_low_bound
+        };
+        Ok((row, col, row * 8 + col))
+    }
+}
+
+fn parse_line(line: &str) -> Result<(u32, u32, u32)> {
+    if line.len() != 10 {
+        Err(AocError::at(
+            line,
+            line,
+            ErrorKind::Invalid(SEAT_LENGTH.to_string()),
+        ))
+    } else {
+        let mut itr = line.char_indices();
+        let row = {
+            let mut row: u32 = 0;
+            for _i in 0..7 {
+                row <<= 1;
+                row += {
+                    match itr.next().unwrap() {
+                        (_, 'F') => 0,
+                        (_, 'B') => 1,
+                        (i, c) => return Err(unexpected(line, i, c)),
+                    }
+                }
+            }
+            row
+        };
+        let col = {
+            let mut col: u32 = 0;
+            for _i in 0..3 {
+                col <<= 1;
+                col += {
+                    match itr.next().unwrap() {
+                        (_, 'L') => 0,
+                        (_, 'R') => 1,
+                        (i, c) => return Err(unexpected(line, i, c)),
+                    }
+                }
+            }
+            col
+        };
+        Ok((row, col, row * 8 + col))
+    }
+}
+
+// Collect and sort by seat ID
+fn parse(file_contents: &str) -> Result<Vec<(u32, u32, u32)>> {
+    let mut v = parse_lines(file_contents, parse_line)?;
+    v.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
+    Ok(v)
+}
+
+/// The missing ID between two taken seats, in IDs sorted by `parse`.
+fn find_seat(seats: &[(u32, u32, u32)]) -> Option<u32> {
+    seats
+        .windows(2)
+        .find(|w| w[0].2 + 2 == w[1].2)
+        .map(|w| w[0].2 + 1)
+}
+
+fn encode_seat(id: u32) -> String {
+    let bit = |i: u32, one: char, zero: char| if id >> i & 1 == 1 { one } else { zero };
+    let row = (3..10).rev().map(|i| bit(i, 'B', 'F'));
+    let col = (0..3).rev().map(|i| bit(i, 'R', 'L'));
+    row.chain(col).collect()
+}
+
+/// A run of at least three boarding passes, in random order, with one seat
+/// missing from the middle.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let count = size.clamp(3, 1000) as u32;
+    let first = rng.range(0, (1023 - count) as i64) as u32;
+    let last = first + count;
+    let missing = rng.range(first as i64 + 2, last as i64 - 1) as u32;
+    let mut ids: Vec<u32> = (first..=last).filter(|&id| id != missing).collect();
+    rng.shuffle(&mut ids);
+    let input: String = ids.iter().map(|&id| encode_seat(id) + "\n").collect();
+    Generated::new(input).part1(last).part2(missing)
+}
+
+static EXAMPLE: &str = "FBFBBFFRLR\nBFFFBBFRRR\nFFFBBBFRRR\nBBFFBBFRLL\n";
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = Vec<(u32, u32, u32)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, seats: &Self::Parsed) -> Answer {
+        seats.iter().last().map(|x| x.2).into()
+    }
+
+    fn part2(&self, seats: &Self::Parsed) -> Answer {
+        find_seat(seats).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(820)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{compare, shrink_string};
+
+    /// Mostly well-formed boarding passes, with the odd wrong letter or
+    /// wrong length.
+    fn random_pass(rng: &mut Rng) -> String {
+        let mut pass: String = (0..10)
+            .map(|i| {
+                if rng.chance(0.05) {
+                    *rng.choose(&['F', 'B', 'L', 'R', 'x', 'é'])
+                } else if i < 7 {
+                    *rng.choose(&['F', 'B'])
+                } else {
+                    *rng.choose(&['L', 'R'])
+                }
+            })
+            .collect();
+        if rng.chance(0.1) {
+            pass.pop();
+        } else if rng.chance(0.1) {
+            pass.push('F');
+        }
+        pass
+    }
+
+    #[test]
+    fn test_differential() {
+        let parse_with = |f: fn(&str) -> Result<(u32, u32, u32)>| {
+            move |line: &String| f(line).map_err(|e| e.to_string())
+        };
+        if let Err(d) = compare(
+            random_pass,
+            |line| shrink_string(line),
+            parse_with(_parse_line),
+            parse_with(parse_line),
+        ) {
+            panic!("_parse_line and parse_line disagree, {}", d);
+        }
+    }
+
+    #[test]
+    fn test_find_seat() {
+        assert_eq!(find_seat(&[]), None);
+        assert_eq!(find_seat(&parse("FFFFFFFLLL\n").unwrap()), None);
+        assert_eq!(find_seat(&parse("FFFFFFFLLL\nFFFFFFFLLL\n").unwrap()), None);
+        let seats = parse("FFFFFFFLLR\nFFFFFFFLLL\nFFFFFFFLRR\n").unwrap();
+        assert_eq!(find_seat(&seats), Some(2));
+    }
+
+    #[test]
+    fn test_parse() {
+        {
+            let (r, c, id) = parse_line("FBFBBFFRLR").unwrap();
+            assert_eq!(r, 44);
+            assert_eq!(c, 5);
+            assert_eq!(id, 357);
+        }
+        {
+            let (r, c, id) = parse_line("BFFFBBFRRR").unwrap();
+            assert_eq!(r, 70);
+            assert_eq!(c, 7);
+            assert_eq!(id, 567);
+        }
+        {
+            let (r, c, id) = parse_line("FFFBBBFRRR").unwrap();
+            assert_eq!(r, 14);
+            assert_eq!(c, 7);
+            assert_eq!(id, 119);
+        }
+        {
+            let (r, c, id) = parse_line("BBFFBBFRLL").unwrap();
+            assert_eq!(r, 102);
+            assert_eq!(c, 4);
+            assert_eq!(id, 820);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::error::{AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use std::collections::HashSet;
+
+fn parse(file: &str) -> Result<String> {
+    match file
+        .char_indices()
+        .find(|&(_, c)| !c.is_ascii_lowercase() && c != '\n')
+    {
+        Some((i, c)) => Err(AocError::at(file, &file[i..], ErrorKind::UnexpectedChar(c))),
+        None => Ok(file.to_string()),
+    }
+}
+
+fn count_part1(file: &str) -> usize {
+    file.split("\n\n")
+        .map(|x| {
+            x.chars()
+                .filter(|x| x.is_alphabetic())
+                .collect::<HashSet<char>>()
+        })
+        .collect::<Vec<_>>()
+        .iter()
+        .map(|x| x.len())
+        .sum()
+}
+
+fn count_part2(file: &str) -> usize {
+    file.split("\n\n")
+        .map(|x| {
+            let mut itr = x.split("\n");
+            let mut first = itr.next().unwrap().chars().collect::<HashSet<char>>();
+            itr.filter(|x| !x.is_empty())
+                .map(|x| x.chars().collect::<HashSet<char>>())
+                .fold(&mut first, |acc, x| {
+                    *acc = acc.intersection(&x).copied().collect::<HashSet<char>>();
+                    acc
+                })
+                .clone()
+        })
+        .collect::<Vec<_>>()
+        .iter()
+        .map(|x| x.len())
+        .sum()
+}
+
+/// `size` groups of one to five people, answers counted with bitmasks as
+/// they're made.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut groups = Vec::new();
+    let (mut anyone, mut everyone) = (0, 0);
+    for _ in 0..size {
+        let (mut union, mut intersection) = (0u32, !0u32 >> 6);
+        let mut group = String::new();
+        for _ in 0..rng.range(1, 5) {
+            let mut answers = 0u32;
+            while answers == 0 {
+                answers = (0..26).filter(|_| rng.chance(0.3)).map(|i| 1 << i).sum();
+            }
+            union |= answers;
+            intersection &= answers;
+            group.extend(
+                (0..26)
+                    .filter(|i| answers >> i & 1 == 1)
+                    .map(|i| (b'a' + i) as char),
+            );
+            group.push('\n');
+        }
+        anyone += union.count_ones();
+        everyone += intersection.count_ones();
+        groups.push(group);
+    }
+    Generated::new(groups.join("\n"))
+        .part1(anyone)
+        .part2(everyone)
+}
+
+static EXAMPLE: &str = r#"abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b"#;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, file: &Self::Parsed) -> Answer {
+        count_part1(file).into()
+    }
+
+    fn part2(&self, file: &Self::Parsed) -> Answer {
+        count_part2(file).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(11).part2(6)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(count_part1(EXAMPLE), 11);
+        assert_eq!(count_part2(EXAMPLE), 6);
+    }
+}
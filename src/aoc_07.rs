@@ -0,0 +1,328 @@
+use crate::error::{parse_num, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+/// For each bag id, the bags it must directly contain as `(count, id)`.
+type Rules = Vec<Vec<(usize, usize)>>;
+
+fn parse(file: &str) -> Result<(Rules, usize)> {
+    let mut bag_names: Vec<String> = Vec::new();
+    let mut get_id = |x: &str| -> usize {
+        match bag_names.iter().enumerate().find(|(_, b)| **b == *x) {
+            Some((idx, _)) => idx,
+            None => {
+                bag_names.push(x.to_string());
+                bag_names.len() - 1
+            }
+        }
+    };
+
+    let re = Regex::new(r"(\d+) ([\w|\s]+) bags?").unwrap();
+
+    let rules_map: BTreeMap<_, _> = file
+        .lines()
+        .map(|x| {
+            static DELIM1: &str = " bags contain ";
+            let idx = x.find(DELIM1).ok_or_else(|| {
+                AocError::at(file, &x[x.len()..], ErrorKind::Missing("` bags contain `"))
+            })?;
+            let beg_slice = &x[..idx];
+            let bag_id = get_id(beg_slice);
+            let end_slice = &x[(idx + DELIM1.len())..];
+            let rule = end_slice
+                .split(",")
+                .filter(|x| !x.is_empty())
+                .filter(|x| *x != "no other bags.")
+                .map(|x| {
+                    let caps = re.captures(x).ok_or_else(|| {
+                        AocError::at(
+                            file,
+                            x.trim_start(),
+                            ErrorKind::Invalid("expected `<count> <color> bags`".to_string()),
+                        )
+                    })?;
+                    let rule_bag_id = get_id(&caps[2]);
+                    let num = parse_num(file, caps.get(1).unwrap().as_str())?;
+                    Ok((num, rule_bag_id))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok((bag_id, rule))
+        })
+        .collect::<Result<_>>()?;
+
+    // Bags that are only ever contained have no line of their own, and hold
+    // nothing.
+    let mut rules: Rules = vec![Vec::new(); bag_names.len()];
+    for (bag_id, rule) in rules_map {
+        rules[bag_id] = rule;
+    }
+
+    for (i, bn) in bag_names.iter().enumerate() {
+        trace!("bag {}: {}", i, bn);
+    }
+
+    let shiny_id = bag_names
+        .iter()
+        .enumerate()
+        .find(|(_, s)| *s == "shiny gold")
+        .ok_or_else(|| AocError::at_end(file, ErrorKind::Missing("rule for shiny gold bags")))?
+        .0;
+
+    Ok((rules, shiny_id))
+}
+
+fn get_containers(rules: &[Vec<(usize, usize)>], id: usize) -> Vec<usize> {
+    rules
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| x.iter().find(|(_, bid)| *bid == id).is_some())
+        .map(|(i, _)| i)
+        .collect()
+}
+
+fn part1(rules: &[Vec<(usize, usize)>], shiny_id: usize) -> usize {
+    let mut queue: VecDeque<usize> = vec![shiny_id].into_iter().collect();
+    let mut colors = HashSet::<usize>::new();
+
+    while !queue.is_empty() {
+        let cur_bag = queue.pop_front().unwrap();
+        let cur_containers = get_containers(rules, cur_bag);
+        for c in cur_containers.iter() {
+            if colors.insert(*c) {
+                queue.push_back(*c);
+            }
+        }
+    }
+
+    colors.len()
+}
+
+/// Whether a bag reachable from `id` ends up holding itself, which would make
+/// the number of bags inside `id` unbounded.
+fn holds_cycle(rules: &[Vec<(usize, usize)>], id: usize) -> bool {
+    fn visit(
+        rules: &[Vec<(usize, usize)>],
+        id: usize,
+        on_path: &mut [bool],
+        done: &mut [bool],
+    ) -> bool {
+        if on_path[id] {
+            return true;
+        }
+        if done[id] {
+            return false;
+        }
+        on_path[id] = true;
+        let found = rules[id]
+            .iter()
+            .any(|&(_, bid)| visit(rules, bid, on_path, done));
+        on_path[id] = false;
+        done[id] = true;
+        found
+    }
+    visit(
+        rules,
+        id,
+        &mut vec![false; rules.len()],
+        &mut vec![false; rules.len()],
+    )
+}
+
+fn part2(rules: &[Vec<(usize, usize)>], shiny_id: usize) -> Option<usize> {
+    if holds_cycle(rules, shiny_id) {
+        return None;
+    }
+    let mut queue: VecDeque<(usize, usize)> = vec![(shiny_id, 1)].into_iter().collect();
+
+    let mut total: usize = 0;
+    while !queue.is_empty() {
+        let (cur_bag, parent_scalar) = queue.pop_front().unwrap();
+        let cur_containees = &rules[cur_bag];
+        for (n, bid) in cur_containees.iter() {
+            queue.push_back((*bid, n * parent_scalar));
+            total += n * parent_scalar;
+        }
+    }
+
+    Some(total)
+}
+
+static ADJECTIVES: [&str; 25] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "dusty", "vivid",
+    "mild", "deep", "soft", "bold", "misty",
+];
+
+static COLORS: [&str; 25] = [
+    "aqua", "beige", "black", "blue", "bronze", "brown", "coral", "crimson", "cyan", "fuchsia",
+    "gold", "gray", "green", "indigo", "lavender", "lime", "magenta", "maroon", "olive", "orange",
+    "plum", "purple", "red", "salmon", "tan",
+];
+
+/// Rules for `size` colors in five layers, shiny gold in the middle one,
+/// where bags only hold bags from the next layer. Both parts walk every path
+/// through the rules, so the layers keep the number of paths small.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let count = size.max(1).min(ADJECTIVES.len() * COLORS.len());
+    let mut names: Vec<String> = ADJECTIVES
+        .iter()
+        .flat_map(|a| COLORS.iter().map(move |c| format!("{} {}", a, c)))
+        .filter(|name| name != "shiny gold")
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(count - 1);
+    let shiny = rng.index(count);
+    names.insert(shiny, "shiny gold".to_string());
+
+    let layer = |i: usize| {
+        if i == shiny {
+            2
+        } else {
+            i * 5 / count
+        }
+    };
+    let mut rules: Vec<Vec<(usize, usize)>> = vec![Vec::new(); count];
+    for (i, rule) in rules.iter_mut().enumerate() {
+        let inner: Vec<usize> = (0..count).filter(|&j| layer(j) == layer(i) + 1).collect();
+        if inner.is_empty() || rng.chance(0.2) {
+            continue;
+        }
+        for _ in 0..rng.range(1, 3) {
+            // Favour shiny gold so that more than a handful of bags hold it.
+            let j = if layer(i) == 1 && rng.chance(0.3) {
+                shiny
+            } else {
+                *rng.choose(&inner)
+            };
+            if rule.iter().all(|&(_, k)| k != j) {
+                rule.push((rng.range(1, 5) as usize, j));
+            }
+        }
+    }
+
+    let mut containers = vec![false; count];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (i, rule) in rules.iter().enumerate() {
+            let holds = rule.iter().any(|&(_, j)| j == shiny || containers[j]);
+            if holds && !containers[i] {
+                containers[i] = true;
+                changed = true;
+            }
+        }
+    }
+    fn contents(rules: &[Vec<(usize, usize)>], bag: usize) -> usize {
+        rules[bag]
+            .iter()
+            .map(|&(n, j)| n * (1 + contents(rules, j)))
+            .sum()
+    }
+
+    let mut lines: Vec<String> = rules
+        .iter()
+        .enumerate()
+        .map(|(i, rule)| {
+            let inner: Vec<String> = rule
+                .iter()
+                .map(|&(n, j)| format!("{} {} bag{}", n, names[j], if n == 1 { "" } else { "s" }))
+                .collect();
+            let inner = if inner.is_empty() {
+                "no other bags".to_string()
+            } else {
+                inner.join(", ")
+            };
+            format!("{} bags contain {}.\n", names[i], inner)
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    Generated::new(lines.concat())
+        .part1(containers.iter().filter(|&&x| x).count())
+        .part2(contents(&rules, shiny))
+}
+
+static EXAMPLE: &str = r#"light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags."#;
+
+static EXAMPLE_2: &str = r#"shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags."#;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = (Rules, usize);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, (rules, shiny_id): &Self::Parsed) -> Answer {
+        part1(rules, *shiny_id).into()
+    }
+
+    fn part2(&self, (rules, shiny_id): &Self::Parsed) -> Answer {
+        part2(rules, *shiny_id).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(EXAMPLE).part1(4).part2(32),
+            Example::new(EXAMPLE_2).part2(126),
+        ]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let (r, s) = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&r, s), 4);
+        assert_eq!(part2(&r, s), Some(32));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (r, s) = parse(EXAMPLE_2).unwrap();
+
+        assert_eq!(part2(&r, s), Some(126));
+    }
+
+    #[test]
+    fn test_uncovered_and_cyclic_rules() {
+        let (r, s) = parse("shiny gold bags contain 1 dark red bag.\n").unwrap();
+        assert_eq!(part1(&r, s), 0);
+        assert_eq!(part2(&r, s), Some(1));
+
+        let (r, s) = parse(
+            "shiny gold bags contain 1 dark red bag.\n\
+             dark red bags contain 2 shiny gold bags.\n",
+        )
+        .unwrap();
+        assert_eq!(part1(&r, s), 2);
+        assert_eq!(part2(&r, s), None);
+    }
+}
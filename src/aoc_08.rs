@@ -0,0 +1,308 @@
+use crate::error::{parse_lines, parse_num, required, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Clone)]
+enum OpCode {
+    Acc,
+    Jmp,
+    Nop,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Instruction {
+    op: OpCode,
+    operand0: Option<i64>,
+}
+
+impl FromStr for Instruction {
+    type Err = AocError;
+    fn from_str(input: &str) -> Result<Instruction> {
+        let mut iter = input.split(' ');
+        let op_str = required(input, iter.next(), "opcode")?;
+        let op_code = OpCode::from_str(op_str).map_err(|e| e.within(input, op_str))?;
+        let operand0 = parse_num(input, required(input, iter.next(), "operand")?)?;
+
+        Ok(Instruction {
+            op: op_code,
+            operand0: Some(operand0),
+        })
+    }
+}
+
+impl FromStr for OpCode {
+    type Err = AocError;
+    fn from_str(input: &str) -> Result<OpCode> {
+        match input {
+            "acc" => Ok(OpCode::Acc),
+            "jmp" => Ok(OpCode::Jmp),
+            "nop" => Ok(OpCode::Nop),
+            _ => Err(AocError::at(
+                input,
+                input,
+                ErrorKind::Unknown {
+                    what: "opcode",
+                    token: input.to_string(),
+                },
+            )),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+struct ProgramState {
+    prog_counter: usize,
+    acc: i64,
+}
+
+impl ProgramState {
+    /// Runs `int`, or returns `None` if it would move the program counter
+    /// before the start of the program.
+    fn run(&mut self, int: &Instruction) -> Option<()> {
+        match int.op {
+            OpCode::Acc => {
+                self.accumulate(int.operand0.unwrap());
+                self.jump(1)
+            }
+            OpCode::Jmp => self.jump(int.operand0.unwrap()),
+            OpCode::Nop => self.jump(1),
+        }
+    }
+
+    fn jump(&mut self, offset: i64) -> Option<()> {
+        let offset = isize::try_from(offset).ok()?;
+        self.prog_counter = self.prog_counter.checked_add_signed(offset)?;
+        Some(())
+    }
+
+    fn accumulate(&mut self, operand: i64) {
+        self.acc += operand;
+    }
+}
+
+fn parse(program_str: &str) -> Result<Vec<Instruction>> {
+    parse_lines(program_str, Instruction::from_str)
+}
+
+/**
+ * Runs the program from a default program state and
+ * calls the callback with the program state after
+ * each instruction is ran. The program counter will
+ * contain the index of the next instruction that will
+ * be ran and the current state of other registers.
+ *
+ * The callback should return true to interrupt execution
+ * and false otherwise.
+ *
+ * Returns the last program state and whether the program
+ * terminated naturally or was interrupted by a callback.
+ * Jumping anywhere past the end terminates the program,
+ * while jumping before the start returns `None`.
+ */
+fn run_with_callback(
+    prog: &[Instruction],
+    step_callback: &mut impl FnMut(&ProgramState) -> bool,
+) -> Option<(ProgramState, bool)> {
+    let mut prog_state = ProgramState::default();
+    let terminate_addr = prog.len();
+    loop {
+        if prog_state.prog_counter >= terminate_addr {
+            return Some((prog_state, true));
+        }
+        prog_state.run(&prog[prog_state.prog_counter])?;
+        if step_callback(&prog_state) {
+            return Some((prog_state, false));
+        }
+    }
+}
+
+fn part_1(prog: &[Instruction]) -> Option<i64> {
+    let mut visited_pcs = HashSet::<usize>::new();
+    visited_pcs.insert(0);
+    let (last_program_state, _terminated) = run_with_callback(prog, &mut |prog_state| {
+        if visited_pcs.contains(&prog_state.prog_counter) {
+            return true;
+        }
+        visited_pcs.insert(prog_state.prog_counter);
+        false
+    })?;
+
+    Some(last_program_state.acc)
+}
+
+fn part_2(prog: &[Instruction]) -> Option<i64> {
+    let nj_inds: Vec<usize> = prog
+        .iter()
+        .enumerate()
+        .filter(|(_, int)| int.op == OpCode::Nop || int.op == OpCode::Jmp)
+        .map(|(i, _int)| i)
+        .collect();
+
+    for i in nj_inds.iter() {
+        let mut modified_program = prog.to_vec();
+        let int_to_mod = &mut modified_program[*i];
+        match int_to_mod.op {
+            OpCode::Acc => panic!("this shouldn't happen"),
+            OpCode::Jmp => {
+                *int_to_mod = Instruction {
+                    op: OpCode::Nop,
+                    operand0: int_to_mod.operand0,
+                }
+            }
+            OpCode::Nop => {
+                *int_to_mod = Instruction {
+                    op: OpCode::Jmp,
+                    operand0: int_to_mod.operand0,
+                }
+            }
+        };
+        let mut seen_program_states = HashSet::<usize>::new();
+        seen_program_states.insert(0);
+        let run = run_with_callback(&modified_program, &mut |prog_state| {
+            if seen_program_states.contains(&prog_state.prog_counter) {
+                return true;
+            }
+            seen_program_states.insert(prog_state.prog_counter);
+            false
+        });
+
+        if let Some((last_program_state, true)) = run {
+            return Some(last_program_state.acc);
+        }
+    }
+    None
+}
+
+/// A program of `size` instructions that runs forwards until a `jmp` at
+/// some index `k` sends it back to the start. Everything before `k` only
+/// jumps forwards to at most `k`, and so does everything after it up to the
+/// end. Changing any instruction before `k` still loops, so the fix is the
+/// `jmp` at `k`.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let len = size.max(1);
+    let k = rng.index(len);
+    let mut prog: Vec<(&str, i64)> = Vec::new();
+    for i in 0..len {
+        let (i, k) = (i as i64, k as i64);
+        // The furthest a jump from here may land.
+        let hi = if i < k { k } else { len as i64 };
+        let instruction = if i == k {
+            ("jmp", -i)
+        } else {
+            match rng.index(4) {
+                0 if i < k => ("nop", rng.range(0, hi) - i),
+                0 => ("nop", rng.range(-100, 100)),
+                1 => ("jmp", rng.range(i + 1, hi) - i),
+                _ => ("acc", rng.range(-50, 50)),
+            }
+        };
+        prog.push(instruction);
+    }
+
+    // Sums the `acc`s run between `from` and `to`, which only jumps forwards.
+    let run = |from: usize, to: usize| {
+        let (mut pc, mut acc) = (from, 0);
+        while pc < to {
+            match prog[pc] {
+                ("acc", x) => acc += x,
+                ("jmp", x) => {
+                    pc = (pc as i64 + x) as usize;
+                    continue;
+                }
+                _ => (),
+            }
+            pc += 1;
+        }
+        acc
+    };
+    let before = run(0, k);
+    let after = run(k + 1, len);
+    let input: String = prog
+        .iter()
+        .map(|(op, x)| format!("{} {:+}\n", op, x))
+        .collect();
+    Generated::new(input).part1(before).part2(before + after)
+}
+
+static EXAMPLE: &str = r#"nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6"#;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, prog: &Self::Parsed) -> Answer {
+        part_1(prog).into()
+    }
+
+    fn part2(&self, prog: &Self::Parsed) -> Answer {
+        part_2(prog).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(5).part2(8)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let parse_res = parse(EXAMPLE);
+        assert!(parse_res.is_ok());
+        let prog = parse_res.unwrap();
+        assert_eq!(part_1(&prog), Some(5));
+
+        let mut adjusted_prog = prog.to_vec();
+        let second_from_end_idx = adjusted_prog.len() - 2;
+        adjusted_prog[second_from_end_idx] = Instruction {
+            op: OpCode::Nop,
+            operand0: Some(-4),
+        };
+        let (ps, terminated) = run_with_callback(&adjusted_prog, &mut |_| false).unwrap();
+        assert!(terminated);
+        assert_eq!(ps.acc, 8);
+    }
+
+    #[test]
+    fn test_out_of_range_jumps() {
+        let prog = parse("acc +2\njmp +5\n").unwrap();
+        assert_eq!(
+            run_with_callback(&prog, &mut |_| false),
+            Some((
+                ProgramState {
+                    prog_counter: 6,
+                    acc: 2
+                },
+                true
+            ))
+        );
+        assert_eq!(part_1(&prog), Some(2));
+
+        let prog = parse("acc +2\njmp -5\n").unwrap();
+        assert_eq!(run_with_callback(&prog, &mut |_| false), None);
+        assert_eq!(part_1(&prog), None);
+        assert_eq!(part_2(&prog), Some(2));
+    }
+}
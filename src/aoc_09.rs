@@ -0,0 +1,259 @@
+use crate::error::{parse_lines, parse_num, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+fn validate(list: &[i64], preamble_size: usize) -> Option<i64> {
+    let mut rolling_pairs = HashMap::<i64, Vec<(i64, i64)>>::new();
+    let preamble: Vec<_> = list.iter().cloned().take(preamble_size).collect();
+    for (i, x) in preamble.iter().cloned().enumerate() {
+        for y in preamble.iter().cloned().skip(i) {
+            if x != y {
+                let sum = x + y;
+                let pair = if x > y { (x, y) } else { (y, x) };
+                rolling_pairs.entry(sum).or_default().push(pair);
+            }
+        }
+    }
+
+    let leading_iter = list.iter().skip(preamble_size);
+    let lagging_iter = list.iter();
+    for (lead, lag) in leading_iter.zip(lagging_iter) {
+        if !rolling_pairs.contains_key(lead) {
+            return Some(*lead);
+        }
+        // (1) find each entry that contains the lagging value and
+        // remove a pair containing it.
+        for (_, v) in rolling_pairs.iter_mut() {
+            if let Some((i, _)) = v
+                .iter()
+                .enumerate()
+                .find(|(_, (x, y))| *x == *lag || *y == *lag)
+            {
+                v.swap_remove(i);
+            };
+        }
+
+        // (2) remove empty pairs
+        rolling_pairs.retain(|_, v| !v.is_empty());
+
+        // (3) get list of remaining individual numbers
+        let mut cur_values = HashSet::<i64>::new();
+        for pairs in rolling_pairs.values() {
+            for (x, y) in pairs.iter() {
+                cur_values.insert(*x);
+                cur_values.insert(*y);
+            }
+        }
+        for i in cur_values.iter() {
+            if *i != *lead {
+                let sum = i + lead;
+                let pair = if i > lead { (*i, *lead) } else { (*lead, *i) };
+                rolling_pairs.entry(sum).or_default().push(pair);
+            }
+        }
+    }
+
+    None
+}
+
+fn find_contiguous(list: &[i64], target: i64) -> Option<(i64, i64)> {
+    for window_size in 2..list.len() {
+        if let Some(matching_window) = list
+            .windows(window_size)
+            .find(|x| x.iter().sum::<i64>() == target)
+        {
+            return Some((
+                *matching_window.iter().min().unwrap(),
+                *matching_window.iter().max().unwrap(),
+            ));
+        }
+    }
+    None
+}
+
+fn parse(input: &str) -> Result<Vec<i64>> {
+    parse_lines(input, |line| parse_num(line, line))
+}
+
+/// Sums of two different numbers in `window`.
+fn pair_sums(window: &[i64]) -> HashSet<i64> {
+    let mut sums = HashSet::new();
+    for (i, x) in window.iter().enumerate() {
+        for y in window[i + 1..].iter().filter(|&y| y != x) {
+            sums.insert(x + y);
+        }
+    }
+    sums
+}
+
+/// Adds a number that's the sum of two in the last 25. It's one of the
+/// smallest unused sums, which keeps a thousand numbers well inside an
+/// `i64`, and never repeats an earlier number.
+fn push_valid(rng: &mut Rng, list: &mut Vec<i64>, used: &mut HashSet<i64>) {
+    let mut sums: Vec<i64> = pair_sums(&list[list.len() - 25..])
+        .difference(used)
+        .copied()
+        .collect();
+    sums.sort_unstable();
+    let x = sums[rng.index(sums.len().min(8))];
+    list.push(x);
+    used.insert(x);
+}
+
+/// Between 27 and 1000 numbers with an invalid one somewhere after the
+/// preamble. The invalid number is the sum of a short contiguous run earlier
+/// on, and no other run adds up to it.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let count = size.clamp(27, 1000);
+    loop {
+        let mut list: Vec<i64> = Vec::new();
+        let mut used = HashSet::new();
+        while list.len() < 25 {
+            let x = rng.range(1, 100);
+            if used.insert(x) {
+                list.push(x);
+            }
+        }
+        let at = rng.range(25, count as i64 - 1) as usize;
+        while list.len() < at {
+            push_valid(rng, &mut list, &mut used);
+        }
+        let sums = pair_sums(&list[at - 25..]);
+        let start = rng.index(at - 1);
+        let len = rng.range(2, (at - start).min(17) as i64) as usize;
+        let run = &list[start..start + len];
+        let target: i64 = run.iter().sum();
+        if sums.contains(&target) {
+            continue;
+        }
+        let weakness = run.iter().min().unwrap() + run.iter().max().unwrap();
+        list.push(target);
+        used.insert(target);
+        while list.len() < count {
+            push_valid(rng, &mut list, &mut used);
+        }
+
+        // Every number is positive, so each run is the difference of two
+        // distinct prefix sums.
+        let mut prefix = vec![0];
+        for x in list.iter() {
+            prefix.push(prefix.last().unwrap() + x);
+        }
+        let starts: HashMap<i64, usize> = prefix.iter().enumerate().map(|(i, &p)| (p, i)).collect();
+        let runs = prefix
+            .iter()
+            .enumerate()
+            .filter(|&(j, p)| matches!(starts.get(&(p - target)), Some(&i) if i + 2 <= j))
+            .count();
+        if runs == 1 {
+            let input: String = list.iter().map(|x| format!("{}\n", x)).collect();
+            return Generated::new(input).part1(target).part2(weakness);
+        }
+    }
+}
+
+static EXAMPLE: &str = r#"35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576"#;
+
+pub struct Day09 {
+    /// How many numbers come before the first one that's checked.
+    pub preamble: usize,
+}
+
+impl Solution for Day09 {
+    type Parsed = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, list: &Self::Parsed) -> Answer {
+        validate(list, self.preamble).into()
+    }
+
+    fn part2(&self, list: &Self::Parsed) -> Answer {
+        validate(list, self.preamble)
+            .and_then(|target| find_contiguous(list, target))
+            .map(|(min, max)| min + max)
+            .into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        // The example only has a preamble of 5.
+        vec![Example::new(EXAMPLE)
+            .part1(127)
+            .part2(62)
+            .solver(&Day09 { preamble: 5 })]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test0() {
+        let base_example: Vec<i64> = (1..26).collect();
+        {
+            let mut ex = base_example.to_vec();
+            ex.push(26);
+            let res = validate(&ex, 25);
+            assert!(res.is_none());
+        }
+        {
+            let mut ex = base_example.to_vec();
+            ex.push(49);
+            let res = validate(&ex, 25);
+            assert!(res.is_none());
+        }
+        {
+            let mut ex = base_example.to_vec();
+            ex.push(100);
+            let res = validate(&ex, 25);
+            assert!(res.is_some());
+            assert_eq!(res.unwrap(), 100);
+        }
+        {
+            let mut ex = base_example.to_vec();
+            ex.push(50);
+            let res = validate(&ex, 25);
+            assert!(res.is_some());
+            assert_eq!(res.unwrap(), 50);
+        }
+    }
+
+    #[test]
+    fn test1() {
+        let example: Vec<i64> = vec![
+            35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309,
+            576,
+        ];
+        let res = validate(&example, 5);
+        assert!(res.is_some());
+        assert_eq!(res.unwrap(), 127);
+    }
+}
@@ -1,6 +1,7 @@
+use crate::error::{parse_lines, parse_num, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
 use std::collections::HashMap;
-use std::env;
-use std::fs;
 
 fn preprocess_jolts(jolts: &[i64]) -> Vec<i64> {
     let mut sorted = jolts.to_vec();
@@ -42,8 +43,7 @@ fn part2(jolts: &[i64]) -> usize {
     // DFS-ish with memoizing
     let mut search_stack = Vec::<i64>::new();
     search_stack.push(0);
-    while !search_stack.is_empty() {
-        let cur_val = search_stack.pop().unwrap();
+    while let Some(cur_val) = search_stack.pop() {
         if memoizer.contains_key(&cur_val) {
             panic!("Don't push a value if you haven't pushed the children first");
         }
@@ -57,10 +57,10 @@ fn part2(jolts: &[i64]) -> usize {
             .collect::<Vec<_>>();
 
         // If all the children are memoized then just sum them up
-        if children.iter().all(|&x| memoizer.contains_key(&x)) {
+        if children.iter().all(|&x| memoizer.contains_key(x)) {
             let children_count = children
                 .iter()
-                .map(|&x| memoizer.get(&x).unwrap())
+                .map(|&x| memoizer.get(x).unwrap())
                 .sum::<usize>();
             memoizer.insert(cur_val, children_count);
         } else {
@@ -80,21 +80,74 @@ fn part2(jolts: &[i64]) -> usize {
     *memoizer.get(&0).unwrap()
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        panic!("We need exactly one arguemnt, got: {}", args.len() - 1);
+fn parse(input: &str) -> Result<Vec<i64>> {
+    parse_lines(input, |line| parse_num(line, line))
+}
+
+/// Adapters in runs of up to four 1-jolt steps separated by 3-jolt steps.
+/// A run of `k` steps can be crossed in `WAYS[k]` ways, and the runs are
+/// independent, so the arrangements are the product. The adapters stop
+/// short of `size` if that product would overflow.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    const WAYS: [i64; 5] = [1, 1, 2, 4, 7];
+    let mut jolts = Vec::new();
+    let mut jolt = 0;
+    // The device's built-in adapter is always 3 higher.
+    let (mut ones, mut threes, mut arrangements) = (0, 1, 1i64);
+    while jolts.len() < size.max(1) {
+        let run = rng.range(0, 4) as usize;
+        arrangements = match arrangements.checked_mul(WAYS[run]) {
+            Some(x) => x,
+            None => break,
+        };
+        for _ in 0..run {
+            jolt += 1;
+            jolts.push(jolt);
+        }
+        ones += run;
+        jolt += 3;
+        jolts.push(jolt);
+        threes += 1;
+    }
+    rng.shuffle(&mut jolts);
+    let input: String = jolts.iter().map(|x| format!("{}\n", x)).collect();
+    Generated::new(input)
+        .part1(ones * threes)
+        .part2(arrangements)
+}
+
+static EXAMPLE: &str = "16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4\n";
+
+static EXAMPLE_2: &str = "28\n33\n18\n42\n31\n14\n46\n20\n48\n47\n24\n23\n49\n45\n19\n38\n39\n11\n1\n32\n25\n35\n8\n17\n7\n9\n4\n2\n34\n10\n3\n";
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, jolts: &Self::Parsed) -> Answer {
+        let (_ones, _threes, p1) = part1(jolts);
+        p1.into()
     }
 
-    let file_contents = fs::read_to_string(&args[1]).expect("couldn't read the file");
-    let jolts: Vec<i64> = file_contents
-        .lines()
-        .map(|x| str::parse::<i64>(x).unwrap())
-        .collect();
+    fn part2(&self, jolts: &Self::Parsed) -> Answer {
+        part2(jolts).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(EXAMPLE).part1(35).part2(8),
+            Example::new(EXAMPLE_2).part1(220).part2(19208),
+        ]
+    }
 
-    let (ones, threes, p1) = part1(&jolts);
-    println!("ones: {}, threes: {}, p1: {}", ones, threes, p1);
-    println!("p2: {}", part2(&jolts));
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,148 @@
+use crate::error::Result;
+use crate::grid::{Grid, NEIGHBORS};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+
+fn parse(grid_str: &str) -> Result<Grid<char>> {
+    Grid::parse(grid_str, |c| if "L#.".contains(c) { Some(c) } else { None })
+}
+
+fn evolve_p1(grid: &mut Grid<char>) -> usize {
+    let mut changed: usize = 0;
+    let mut grid_copy = grid.clone();
+
+    for ((x, y), &cur) in grid.positions() {
+        if cur == '.' {
+            continue;
+        }
+
+        let occupied_neighbors = grid.neighbors(x, y).filter(|&xy| grid[xy] == '#').count();
+
+        if cur == '#' && occupied_neighbors >= 4 {
+            grid_copy[(x, y)] = 'L';
+            changed += 1;
+        } else if cur == 'L' && occupied_neighbors == 0 {
+            grid_copy[(x, y)] = '#';
+            changed += 1;
+        }
+    }
+    *grid = grid_copy;
+
+    changed
+}
+
+fn evolve_p2(grid: &mut Grid<char>) -> usize {
+    let mut changed: usize = 0;
+    let mut grid_copy = grid.clone();
+
+    for ((x, y), &cur) in grid.positions() {
+        if cur == '.' {
+            continue;
+        }
+
+        let occupied_neighbors = NEIGHBORS
+            .iter()
+            .map(|(xd, yd)| {
+                let mut cur_x = x as i64;
+                let mut cur_y = y as i64;
+                loop {
+                    cur_x += xd;
+                    cur_y += yd;
+
+                    match grid.get(cur_x, cur_y) {
+                        None => return '.',
+                        Some('L') => return 'L',
+                        Some('#') => return '#',
+                        _ => (),
+                    };
+                }
+            })
+            .filter(|&n| n == '#')
+            .count();
+
+        if cur == '#' && occupied_neighbors >= 5 {
+            grid_copy[(x, y)] = 'L';
+            changed += 1;
+        } else if cur == 'L' && occupied_neighbors == 0 {
+            grid_copy[(x, y)] = '#';
+            changed += 1;
+        }
+    }
+    *grid = grid_copy;
+
+    changed
+}
+
+fn settle(grid: &Grid<char>, evolve: fn(&mut Grid<char>) -> usize) -> usize {
+    let mut grid = grid.clone();
+    loop {
+        let changed = evolve(&mut grid);
+        if changed == 0 {
+            break;
+        }
+    }
+
+    grid.iter().filter(|&x| *x == '#').count()
+}
+
+/// A seat layout `size` rows tall and at most 100 wide, mostly seats like
+/// the real inputs. There's no shortcut to where the seating settles, so no
+/// answers.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let width = size.min(100);
+    let mut input = String::new();
+    for _ in 0..size {
+        input.extend((0..width).map(|_| if rng.chance(0.15) { '.' } else { 'L' }));
+        input.push('\n');
+    }
+    Generated::new(input)
+}
+
+static EXAMPLE: &str = r#"L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL"#;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, grid: &Self::Parsed) -> Answer {
+        settle(grid, evolve_p1).into()
+    }
+
+    fn part2(&self, grid: &Self::Parsed) -> Answer {
+        settle(grid, evolve_p2).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(37).part2(26)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let grid = parse(EXAMPLE).unwrap();
+        assert_eq!(settle(&grid, evolve_p1), 37);
+        assert_eq!(settle(&grid, evolve_p2), 26);
+    }
+}
@@ -0,0 +1,249 @@
+use crate::error::{parse_lines, parse_num, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+
+#[derive(Debug, Clone)]
+struct ShipState {
+    heading: i32,
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Clone)]
+struct ShipWaypointState {
+    ship_x: i32,
+    ship_y: i32,
+    waypoint_x: i32,
+    waypoint_y: i32,
+}
+
+fn ship_step_p1(ship: ShipState, command: char, val: i32) -> ShipState {
+    let mut new_state = ship.clone();
+    match command {
+        'N' => new_state.y -= val,
+        'S' => new_state.y += val,
+        'E' => new_state.x += val,
+        'W' => new_state.x -= val,
+        'L' => new_state.heading += val,
+        'R' => new_state.heading -= val,
+        'F' => match new_state.heading {
+            0 => new_state.x += val,
+            90 => new_state.y -= val,
+            180 => new_state.x -= val,
+            270 => new_state.y += val,
+            _ => panic!("shouldn't happen"),
+        },
+        _ => panic!("Unknown Command!"),
+    };
+
+    if new_state.heading >= 360 {
+        new_state.heading %= 360;
+    } else if new_state.heading < 0 {
+        let flipped = (-new_state.heading) % 360;
+        new_state.heading = 360 - flipped;
+    }
+
+    new_state
+}
+
+fn step_p2(state: ShipWaypointState, command: char, val: i32) -> ShipWaypointState {
+    let mut new_state = state.clone();
+    match command {
+        'N' => new_state.waypoint_y -= val,
+        'S' => new_state.waypoint_y += val,
+        'E' => new_state.waypoint_x += val,
+        'W' => new_state.waypoint_x -= val,
+        'R' => match val {
+            90 => {
+                new_state.waypoint_x = -state.waypoint_y;
+                new_state.waypoint_y = state.waypoint_x;
+            }
+            180 => {
+                new_state.waypoint_x = -state.waypoint_x;
+                new_state.waypoint_y = -state.waypoint_y;
+            }
+            270 => {
+                new_state.waypoint_x = state.waypoint_y;
+                new_state.waypoint_y = -state.waypoint_x;
+            }
+            _ => panic!("unknown rotation"),
+        },
+        'L' => match val {
+            90 => {
+                new_state.waypoint_x = state.waypoint_y;
+                new_state.waypoint_y = -state.waypoint_x;
+            }
+            180 => {
+                new_state.waypoint_x = -state.waypoint_x;
+                new_state.waypoint_y = -state.waypoint_y;
+            }
+            270 => {
+                new_state.waypoint_x = -state.waypoint_y;
+                new_state.waypoint_y = state.waypoint_x;
+            }
+            _ => panic!("unknown rotation"),
+        },
+        'F' => {
+            new_state.ship_x += new_state.waypoint_x * val;
+            new_state.ship_y += new_state.waypoint_y * val;
+        }
+        _ => panic!("Unknown Command!"),
+    };
+
+    new_state
+}
+
+fn parse(input_str: &str) -> Result<Vec<(char, i32)>> {
+    parse_lines(input_str, |x| {
+        let command = x.chars().next().unwrap();
+        if !"NSEWLRF".contains(command) {
+            return Err(AocError::at(x, x, ErrorKind::UnexpectedChar(command)));
+        }
+        let val_slice = &x[command.len_utf8()..];
+        let val = parse_num(x, val_slice)?;
+        if (command == 'L' || command == 'R') && ![90, 180, 270].contains(&val) {
+            return Err(AocError::at(
+                x,
+                val_slice,
+                ErrorKind::Invalid(format!("can't turn by {} degrees", val)),
+            ));
+        }
+        Ok((command, val))
+    })
+}
+
+fn navigate_p1(commands: &[(char, i32)]) -> i32 {
+    let mut ss = ShipState {
+        heading: 0,
+        x: 0,
+        y: 0,
+    };
+    for command in commands.iter() {
+        ss = ship_step_p1(ss, command.0, command.1);
+    }
+    ss.x.abs() + ss.y.abs()
+}
+
+fn navigate_p2(commands: &[(char, i32)]) -> i32 {
+    let mut sws = ShipWaypointState {
+        ship_x: 0,
+        ship_y: 0,
+        waypoint_x: 10,
+        waypoint_y: -1,
+    };
+    for command in commands.iter() {
+        sws = step_p2(sws, command.0, command.1);
+    }
+    sws.ship_x.abs() + sws.ship_y.abs()
+}
+
+/// `size` random instructions, followed with both sets of rules as they're
+/// made. The instructions stop early if either ship could drift far enough
+/// to overflow.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    // Positions and waypoints as (east, north).
+    let (mut ship, mut heading) = ((0i64, 0i64), (1i64, 0i64));
+    let (mut ship2, mut waypoint) = ((0i64, 0i64), (10i64, 1i64));
+    let mut input = String::new();
+    for _ in 0..size {
+        let action = *rng.choose(&['N', 'S', 'E', 'W', 'L', 'R', 'F']);
+        let value = match action {
+            'L' | 'R' => 90 * rng.range(1, 3),
+            'F' => rng.range(1, 100),
+            _ => rng.range(1, 5),
+        };
+        match action {
+            'L' | 'R' => {
+                let turns = if action == 'L' {
+                    value / 90
+                } else {
+                    4 - value / 90
+                };
+                for _ in 0..turns {
+                    heading = (-heading.1, heading.0);
+                    waypoint = (-waypoint.1, waypoint.0);
+                }
+            }
+            'F' => {
+                ship = (ship.0 + heading.0 * value, ship.1 + heading.1 * value);
+                ship2 = (ship2.0 + waypoint.0 * value, ship2.1 + waypoint.1 * value);
+            }
+            _ => {
+                let (dx, dy) = match action {
+                    'N' => (0, value),
+                    'S' => (0, -value),
+                    'E' => (value, 0),
+                    _ => (-value, 0),
+                };
+                ship = (ship.0 + dx, ship.1 + dy);
+                waypoint = (waypoint.0 + dx, waypoint.1 + dy);
+            }
+        }
+        input += &format!("{}{}\n", action, value);
+        // The next `F` moves the second ship at most 100 waypoints.
+        let far = |(x, y): (i64, i64), limit: i32| x.abs() + y.abs() > limit as i64;
+        if far(ship2, i32::MAX / 4) || far(waypoint, i32::MAX / 400) {
+            break;
+        }
+    }
+    Generated::new(input)
+        .part1(ship.0.abs() + ship.1.abs())
+        .part2(ship2.0.abs() + ship2.1.abs())
+}
+
+static EXAMPLE: &str = r#"F10
+N3
+F7
+R90
+F11"#;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Vec<(char, i32)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, commands: &Self::Parsed) -> Answer {
+        navigate_p1(commands).into()
+    }
+
+    fn part2(&self, commands: &Self::Parsed) -> Answer {
+        navigate_p2(commands).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(25).part2(286)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let commands = parse(EXAMPLE).unwrap();
+        assert_eq!(navigate_p1(&commands), 25);
+        assert_eq!(navigate_p2(&commands), 286);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("F10\nN3x").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 2: invalid number `3x`");
+        let err = parse("F10\nR45").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = parse("Q1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: unexpected character `Q`"
+        );
+    }
+}
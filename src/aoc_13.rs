@@ -0,0 +1,242 @@
+use crate::error::{parse_num, required, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+fn gcd(a: i64, b: i64) -> i64 {
+    match ((a, b), (a & 1, b & 1)) {
+        ((x, y), _) if x == y => y,
+        ((0, x), _) | ((x, 0), _) => x,
+        ((x, y), (0, 1)) | ((y, x), (1, 0)) => gcd(x >> 1, y),
+        ((x, y), (0, 0)) => gcd(x >> 1, y >> 1) << 1,
+        ((x, y), (1, 1)) => {
+            let (x, y) = (std::cmp::min(x, y), std::cmp::max(x, y));
+            gcd((y - x) >> 1, x)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn lcm(a: i64, b: i64) -> i64 {
+    a * b / gcd(a, b)
+}
+
+fn find_first_dep_p1(min_dep_time: i64, buses: &[Option<i64>]) -> (i64, i64) {
+    let mut dep = min_dep_time;
+    loop {
+        for b in buses.iter() {
+            if b.is_some() && dep % b.unwrap() == 0 {
+                return (dep, b.unwrap());
+            }
+        }
+
+        dep += 1;
+    }
+}
+
+fn find_phase(a: i64, b: i64, offset: i64) -> i64 {
+    trace!("find_phase: {} {} {}", a, b, offset);
+    let mut x = 0;
+    loop {
+        if (offset + x) % b == 0 {
+            return offset + x;
+        }
+
+        x += a;
+    }
+}
+
+fn find_p2(buses: &[Option<i64>]) -> i64 {
+    let buses_offsets: Vec<(i64, i64)> = buses
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| b.is_some())
+        .map(|(i, b)| (i as i64, b.unwrap()))
+        .collect();
+
+    let offsets = buses_offsets.iter().map(|(i, _)| *i).collect::<Vec<_>>();
+    debug!("offsets: {:?}", offsets);
+
+    let mut old_freq = buses_offsets.first().unwrap().1;
+    let mut old_offset = 0;
+    for &(offset, freq) in buses_offsets.iter().skip(1) {
+        debug!("old: {} {}, new: {} {}", old_freq, old_offset, freq, offset);
+
+        let new_phase = find_phase(old_freq, freq, offset + old_offset) - offset;
+        let new_freq = lcm(old_freq, freq);
+
+        debug!("phase: {}, freq: {}", new_phase, new_freq);
+        old_freq = new_freq;
+        old_offset = new_phase;
+    }
+
+    old_offset
+}
+
+fn parse(input: &str) -> Result<(i64, Vec<Option<i64>>)> {
+    let mut line_itr = input.lines();
+
+    let departure_time = parse_num(input, required(input, line_itr.next(), "departure time")?)?;
+
+    let bus_line = required(input, line_itr.next(), "bus ids")?;
+    let bus_ids = bus_line
+        .split(',')
+        .map(|x| match x {
+            "x" => Ok(None),
+            _ => match parse_num(input, x)? {
+                id if id > 0 => Ok(Some(id)),
+                _ => Err(AocError::at(
+                    input,
+                    x,
+                    ErrorKind::Invalid(format!("bus id `{}` is not positive", x)),
+                )),
+            },
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if bus_ids.iter().all(Option::is_none) {
+        return Err(AocError::at(input, bus_line, ErrorKind::Missing("bus id")));
+    }
+
+    Ok((departure_time, bus_ids))
+}
+
+/// Buses with distinct prime ids whose product fits comfortably in an
+/// `i64`. The earliest timestamp `t` for part 2 is picked first and each bus
+/// is placed at the index `i` where `t + i` is a multiple of its id, so the
+/// answer is `t` as long as it's below the product. The list is padded
+/// with `x` to `size` entries.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let is_prime = |n: i64| (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0);
+    let (ids, t, positions) = loop {
+        let mut ids: Vec<i64> = Vec::new();
+        let mut product = 1i64;
+        while ids.len() < 9 {
+            let id = rng.range(7, 999);
+            if !is_prime(id) || ids.contains(&id) {
+                continue;
+            }
+            match product.checked_mul(id) {
+                Some(x) if x < 1 << 62 => product = x,
+                _ => break,
+            }
+            ids.push(id);
+        }
+        let t = ids[0] * rng.below((product / ids[0]) as u64) as i64;
+        let positions: Vec<usize> = ids.iter().map(|id| (-t).rem_euclid(*id) as usize).collect();
+        let distinct = positions
+            .iter()
+            .enumerate()
+            .all(|(i, p)| !positions[i + 1..].contains(p));
+        if distinct {
+            break (ids, t, positions);
+        }
+    };
+
+    let len = positions.iter().max().unwrap() + 1;
+    let mut schedule = vec!["x".to_string(); len.max(size)];
+    for (id, &p) in ids.iter().zip(positions.iter()) {
+        schedule[p] = id.to_string();
+    }
+    let departure = rng.range(1000, 1_000_000);
+    // The first bus to leave, taking the earliest in the list on a tie.
+    let (wait, _, id) = ids
+        .iter()
+        .zip(positions.iter())
+        .map(|(id, p)| ((departure + id - 1) / id * id - departure, p, id))
+        .min()
+        .unwrap();
+    Generated::new(format!("{}\n{}\n", departure, schedule.join(",")))
+        .part1(wait * id)
+        .part2(t)
+}
+
+static EXAMPLE: &str = r#"939
+7,13,x,x,59,x,31,19"#;
+
+static EXAMPLE_2: &str = r#"939
+1789,37,47,1889"#;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = (i64, Vec<Option<i64>>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, (departure, buses): &Self::Parsed) -> Answer {
+        let (dep, b) = find_first_dep_p1(*departure, buses);
+        (b * (dep - departure)).into()
+    }
+
+    fn part2(&self, (_departure, buses): &Self::Parsed) -> Answer {
+        find_p2(buses).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(EXAMPLE).part1(295).part2(1068781),
+            Example::new(EXAMPLE_2).part2(1202161486),
+        ]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let (departure, buses) = parse(EXAMPLE).unwrap();
+        assert_eq!(departure, 939);
+        let truth: Vec<Option<i64>> = vec![
+            Some(7),
+            Some(13),
+            None,
+            None,
+            Some(59),
+            None,
+            Some(31),
+            Some(19),
+        ];
+        assert_eq!(buses, truth);
+
+        let (dep, b) = find_first_dep_p1(departure, &buses);
+
+        assert_eq!(dep, 944);
+        assert_eq!(b, 59);
+
+        let ts = find_p2(&buses);
+        assert_eq!(ts, 1068781);
+    }
+
+    #[test]
+    fn test_invalid_ids() {
+        assert_eq!(
+            parse("939\n7,x,0\n"),
+            Err(AocError::new(
+                2,
+                5,
+                ErrorKind::Invalid("bus id `0` is not positive".to_string())
+            ))
+        );
+        assert_eq!(
+            parse("939\n7,-13\n").unwrap_err().kind,
+            ErrorKind::Invalid("bus id `-13` is not positive".to_string())
+        );
+        assert_eq!(
+            parse("939\nx,x\n"),
+            Err(AocError::new(2, 1, ErrorKind::Missing("bus id")))
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let (_departure, buses) = parse(EXAMPLE_2).unwrap();
+        let ts = find_p2(&buses);
+        assert_eq!(ts, 1202161486);
+    }
+}
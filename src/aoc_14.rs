@@ -0,0 +1,238 @@
+use crate::error::{parse_lines, parse_num, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+struct ProgramState {
+    addr_mask: usize,
+    float_mask: usize,
+    memory: HashMap<usize, usize>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Mask { addr_mask: usize, float_mask: usize },
+    Assignment { addr: usize, value: usize },
+}
+
+fn run_p2(insts: &[Instruction]) -> usize {
+    let mut writes: Vec<(usize, usize, usize)> = Vec::new();
+    let mut ps = ProgramState {
+        addr_mask: 0,
+        float_mask: 0,
+        memory: HashMap::new(),
+    };
+
+    for i in insts.iter() {
+        match i {
+            Instruction::Mask {
+                addr_mask,
+                float_mask,
+            } => {
+                ps.addr_mask = *addr_mask;
+                ps.float_mask = *float_mask;
+            }
+            Instruction::Assignment { addr, value } => {
+                writes.push((
+                    *value,
+                    ps.float_mask,
+                    (addr & !ps.float_mask) | ps.addr_mask,
+                ));
+            }
+        }
+    }
+
+    for (v, f, addr) in writes.iter() {
+        let mut floating_bits: Vec<usize> = Vec::new();
+        {
+            let mut f_shift = *f;
+            for i in 0..64 {
+                if f_shift == 0 {
+                    break;
+                }
+                if f_shift & 1 == 1 {
+                    floating_bits.push(i);
+                }
+
+                f_shift >>= 1;
+            }
+        }
+
+        let num_perms = 1 << floating_bits.len();
+        for i in 0..num_perms {
+            let mut set_bits: Vec<usize> = Vec::new();
+            let mut clear_bits: Vec<usize> = Vec::new();
+            {
+                let mut i_shift = i;
+                for i in 0..floating_bits.len() {
+                    if i_shift & 1 == 1 {
+                        set_bits.push(i);
+                    } else if i_shift & 1 == 0 {
+                        clear_bits.push(i);
+                    }
+
+                    i_shift >>= 1;
+                }
+            }
+            let mut addr_mut = *addr;
+            for s in set_bits {
+                addr_mut |= 1 << floating_bits[s];
+            }
+            for c in clear_bits {
+                addr_mut &= !(1 << floating_bits[c]);
+            }
+            ps.memory.insert(addr_mut, *v);
+        }
+    }
+
+    let mut mem_sum = 0;
+    for (_, v) in ps.memory.iter() {
+        mem_sum += v;
+    }
+
+    mem_sum
+}
+
+fn parse_line(l: &str) -> Result<Instruction> {
+    let equals_sign = l
+        .find(" = ")
+        .ok_or_else(|| AocError::at(l, &l[l.len()..], ErrorKind::Missing("` = `")))?;
+    let target = &l[..equals_sign];
+    let val_slice = &l[equals_sign + 3..];
+    if target == "mask" {
+        if let Some((i, c)) = val_slice.char_indices().find(|&(_, c)| !"01X".contains(c)) {
+            return Err(AocError::at(
+                l,
+                &val_slice[i..],
+                ErrorKind::UnexpectedChar(c),
+            ));
+        }
+        if val_slice.len() != 36 {
+            return Err(AocError::at(
+                l,
+                val_slice,
+                ErrorKind::Invalid("expected a 36 bit mask".to_string()),
+            ));
+        }
+        let float_mask_str = val_slice.replace('1', "0").replace('X', "1");
+        let float_mask = usize::from_str_radix(&float_mask_str, 2).unwrap();
+        let addr_mask_str = val_slice.replace('X', "0");
+        let addr_mask = usize::from_str_radix(&addr_mask_str, 2).unwrap();
+        Ok(Instruction::Mask {
+            addr_mask,
+            float_mask,
+        })
+    } else if target.starts_with("mem[") && target.ends_with(']') {
+        let addr = parse_num(l, &target[4..target.len() - 1])?;
+        let value = parse_num(l, val_slice)?;
+        Ok(Instruction::Assignment { addr, value })
+    } else {
+        Err(AocError::at(
+            l,
+            target,
+            ErrorKind::Unknown {
+                what: "instruction",
+                token: target.to_string(),
+            },
+        ))
+    }
+}
+
+fn parse(input_str: &str) -> Result<Vec<Instruction>> {
+    parse_lines(input_str, parse_line)
+}
+
+/// About `size` lines of masks, each followed by a few writes. Masks have at
+/// most nine floating bits to keep the number of addresses down, and part 2
+/// is worked out by writing to every address each write decodes to.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut memory = HashMap::new();
+    let mut lines = 0;
+    while lines < size.max(2) {
+        let mut mask: Vec<char> = (0..36)
+            .map(|_| if rng.chance(0.5) { '1' } else { '0' })
+            .collect();
+        for _ in 0..rng.range(0, 9) {
+            mask[rng.index(36)] = 'X';
+        }
+        let mask: String = mask.into_iter().collect();
+        let ones = usize::from_str_radix(&mask.replace('X', "0"), 2).unwrap();
+        let floating = usize::from_str_radix(&mask.replace('1', "0").replace('X', "1"), 2).unwrap();
+        input += &format!("mask = {}\n", mask);
+        lines += 1;
+        for _ in 0..rng.range(1, 5) {
+            let addr = rng.range(0, 65535) as usize;
+            let value = rng.range(0, 100_000_000) as usize;
+            input += &format!("mem[{}] = {}\n", addr, value);
+            lines += 1;
+            // Counts down through every subset of the floating bits.
+            let base = (addr | ones) & !floating;
+            let mut bits = floating;
+            loop {
+                memory.insert(base | bits, value);
+                if bits == 0 {
+                    break;
+                }
+                bits = (bits - 1) & floating;
+            }
+        }
+    }
+    Generated::new(input).part2(memory.values().sum::<usize>())
+}
+
+static EXAMPLE: &str = r#"mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1"#;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, _insts: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(&self, insts: &Self::Parsed) -> Answer {
+        (run_p2(insts) as i64).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part2(208)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let insts = parse(EXAMPLE).unwrap();
+        assert_eq!(run_p2(&insts), 208);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("mask = 0X1\nmem[8] = 11").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 8: expected a 36 bit mask");
+        let err = parse("mem[7] = 101\nmov[8] = 11").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: unknown instruction `mov[8]`"
+        );
+        let err = parse("mem[a] = 101").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+    }
+}
@@ -0,0 +1,169 @@
+use crate::error::{parse_num, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use std::collections::HashMap;
+
+fn last_turn_p1(v: &[i64], last: i64) -> i64 {
+    let mut growing = v.to_vec();
+    let starting = v.len() as i64;
+    for i in 0..last {
+        if i < starting {
+            continue;
+        }
+        let last_number = growing.last().unwrap();
+        let found = growing
+            .iter()
+            .enumerate()
+            .rev()
+            .skip(1)
+            .find(|(_, x)| *x == last_number);
+        let new_val = match found {
+            Some((i, _)) => (growing.len() - 1 - i) as i64,
+            None => 0,
+        };
+
+        growing.push(new_val);
+    }
+    *growing.last().unwrap()
+}
+
+fn last_turn_p2(v: &[i64], last: i64) -> i64 {
+    let starting = v.len() as i64;
+    let mut map = HashMap::<i64, i64>::new();
+    for (i, x) in v.iter().take((starting - 1) as usize).enumerate() {
+        map.insert(*x, i as i64);
+        //   print!("{}, ", x);
+    }
+    let mut last_val = *v.last().unwrap();
+    //print!("{}, ", last_val);
+    for i in 0..last {
+        trace!("turn {}", i);
+        if i < starting {
+            continue;
+        }
+        // let last_number = growing.last().unwrap();
+
+        let new_val = if map.contains_key(&last_val) {
+            i - 1 - map[&last_val]
+        } else {
+            0
+        };
+        //   print!("{}, ", new_val);
+
+        map.insert(last_val, i - 1);
+        last_val = new_val;
+    }
+    //print!("\n");
+    last_val
+}
+
+fn parse(input: &str) -> Result<Vec<i64>> {
+    input
+        .trim()
+        .split(',')
+        .map(|x| parse_num(input, x))
+        .collect()
+}
+
+/// Up to `size` distinct starting numbers, at most ten. Part 1 comes from
+/// replaying the game turn by turn; part 2 is too long for that.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut starting: Vec<i64> = (0..20).collect();
+    rng.shuffle(&mut starting);
+    starting.truncate(size.clamp(1, 10));
+    let input = starting.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+    Generated::new(input.join(",") + "\n").part1(last_turn_p1(&starting, 2020))
+}
+
+static EXAMPLE: &str = "0,3,6\n";
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, starting: &Self::Parsed) -> Answer {
+        last_turn_p2(starting, 2020).into()
+    }
+
+    fn part2(&self, starting: &Self::Parsed) -> Answer {
+        last_turn_p2(starting, 30000000).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(EXAMPLE).part1(436).part2(175594),
+            Example::new("1,3,2\n").part1(1),
+            Example::new("2,1,3\n").part1(10),
+            Example::new("1,2,3\n").part1(27),
+            Example::new("2,3,1\n").part1(78),
+            Example::new("3,2,1\n").part1(438),
+            Example::new("3,1,2\n").part1(1836),
+        ]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{compare, shrink_vec};
+
+    /// Fewer turns, fewer starting numbers or smaller ones.
+    fn shrink((starting, turns): &(Vec<i64>, i64)) -> Vec<(Vec<i64>, i64)> {
+        let mut shrinks: Vec<_> = shrink_vec(starting)
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .map(|s| (s, *turns))
+            .collect();
+        for i in 0..starting.len() {
+            if starting[i] > 0 {
+                let mut smaller = starting.clone();
+                smaller[i] -= 1;
+                shrinks.push((smaller, *turns));
+            }
+        }
+        if *turns > 0 {
+            shrinks.push((starting.clone(), turns / 2));
+            shrinks.push((starting.clone(), turns - 1));
+        }
+        shrinks
+    }
+
+    #[test]
+    fn test_differential() {
+        let generate = |rng: &mut Rng| {
+            let count = rng.range(1, 6);
+            let starting = (0..count).map(|_| rng.range(0, 9)).collect();
+            (starting, rng.range(0, 300))
+        };
+        let run_with = |f: fn(&[i64], i64) -> i64| move |(v, turns): &(Vec<i64>, i64)| f(v, *turns);
+        if let Err(d) = compare(
+            generate,
+            shrink,
+            run_with(last_turn_p1),
+            run_with(last_turn_p2),
+        ) {
+            panic!("last_turn_p1 and last_turn_p2 disagree, {}", d);
+        }
+    }
+
+    #[test]
+    fn test() {
+        assert_eq!(last_turn_p2(&[0, 3, 6], 10), 0);
+        assert_eq!(last_turn_p2(&[0, 3, 6], 2020), 436);
+        assert_eq!(last_turn_p2(&[1, 3, 2], 2020), 1);
+        assert_eq!(last_turn_p2(&[2, 1, 3], 2020), 10);
+        assert_eq!(last_turn_p2(&[1, 2, 3], 2020), 27);
+        assert_eq!(last_turn_p2(&[2, 3, 1], 2020), 78);
+        assert_eq!(last_turn_p2(&[3, 2, 1], 2020), 438);
+        assert_eq!(last_turn_p2(&[3, 1, 2], 2020), 1836);
+    }
+}
@@ -0,0 +1,321 @@
+use crate::error::{parse_lines, parse_num, required, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+type RuleRanges = ((i64, i64), (i64, i64));
+
+#[derive(Debug)]
+pub struct Notes {
+    rules: HashMap<String, RuleRanges>,
+    your_ticket: Vec<i64>,
+    nearby_tickets: Vec<Vec<i64>>,
+}
+
+fn eval_rule(val: i64, range: &RuleRanges) -> bool {
+    (val >= range.0 .0 && val <= range.0 .1) || (val >= range.1 .0 && val <= range.1 .1)
+}
+
+fn invalid_ticket_vals(ticket: &[i64], rules: &HashMap<String, RuleRanges>) -> Vec<i64> {
+    ticket
+        .iter()
+        .filter_map(|x| {
+            if !rules.values().any(|r| eval_rule(*x, r)) {
+                Some(*x)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn ticket_scan_error_rate(notes: &Notes) -> i64 {
+    notes
+        .nearby_tickets
+        .iter()
+        .flat_map(|x| invalid_ticket_vals(x, &notes.rules))
+        .sum()
+}
+
+fn p2_notes_filter(notes: &Notes) -> Notes {
+    let filtered_nearby_tickets = notes
+        .nearby_tickets
+        .iter()
+        .filter(|x| invalid_ticket_vals(x, &notes.rules).is_empty())
+        .cloned()
+        .collect::<Vec<Vec<i64>>>();
+
+    Notes {
+        rules: notes.rules.clone(),
+        your_ticket: notes.your_ticket.to_vec(),
+        nearby_tickets: filtered_nearby_tickets,
+    }
+}
+
+fn parse_ticket(line: &str) -> Result<Vec<i64>> {
+    line.split(',').map(|x| parse_num(line, x)).collect()
+}
+
+fn parse(input: &str) -> Result<Notes> {
+    let mut iter = input.split("\n\n").filter(|x| !x.is_empty());
+
+    let re = Regex::new(r"^(.*): (\d+)-(\d+) or (\d+)-(\d+)$").unwrap();
+
+    let rules_section = required(input, iter.next(), "ticket rules")?;
+    let rules: HashMap<String, RuleRanges> = parse_lines(rules_section, |x| {
+        let caps = re.captures(x).ok_or_else(|| {
+            AocError::at(
+                x,
+                x,
+                ErrorKind::Invalid("expected `<field>: <a>-<b> or <c>-<d>`".to_string()),
+            )
+        })?;
+        let label = caps.get(1).unwrap().as_str().to_string();
+        let num = |i| parse_num(x, caps.get(i).unwrap().as_str());
+
+        Ok((label, ((num(2)?, num(3)?), (num(4)?, num(5)?))))
+    })
+    .map_err(|e| e.within(input, rules_section))?
+    .into_iter()
+    .collect();
+
+    let your_section = required(input, iter.next(), "your ticket")?;
+    let your_line = required(input, your_section.lines().nth(1), "your ticket")?;
+    let your_ticket = parse_ticket(your_line).map_err(|e| e.within(input, your_line))?;
+
+    let nearby_section = required(input, iter.next(), "nearby tickets")?;
+    let nearby_tickets = nearby_section
+        .lines()
+        .skip(1)
+        .filter(|l| !l.is_empty())
+        .map(|l| parse_ticket(l).map_err(|e| e.within(input, l)))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Notes {
+        rules,
+        your_ticket,
+        nearby_tickets,
+    })
+}
+
+/// The product of your ticket's departure fields, or `None` if the tickets
+/// don't pin down one field per column.
+fn departure_product(notes: &Notes) -> Option<i64> {
+    let filtered_notes = p2_notes_filter(notes);
+
+    let tickets: Vec<Vec<i64>> = filtered_notes
+        .nearby_tickets
+        .iter()
+        .cloned()
+        .chain(std::iter::once(filtered_notes.your_ticket))
+        .collect();
+
+    let ticket_len = tickets[0].len();
+    if tickets.iter().any(|t| t.len() != ticket_len) {
+        return None;
+    }
+
+    let rules = filtered_notes.rules;
+
+    let mut possible_rules: Vec<(i64, Vec<&str>)> = Vec::new();
+    for i in 0..ticket_len {
+        let mut pr: Vec<&str> = Vec::new();
+
+        for (k, v) in rules.iter() {
+            if tickets.iter().all(|t| {
+                let val = t[i];
+                eval_rule(val, v)
+            }) {
+                pr.push(k);
+            }
+        }
+        pr.sort_by(|a, b| a.len().partial_cmp(&b.len()).unwrap());
+        possible_rules.push((i as i64, pr));
+    }
+    possible_rules.sort_by(|a, b| a.1.len().partial_cmp(&b.1.len()).unwrap());
+
+    let mut shrinking_rules = possible_rules.to_vec();
+    let mut result: BTreeMap<&str, i64> = BTreeMap::new();
+    while result.len() < ticket_len {
+        let (i, pr) = shrinking_rules.iter().find(|(_, v)| v.len() == 1)?;
+        let label: &str = pr[0];
+        result.insert(label, *i);
+        for (_, v) in shrinking_rules.iter_mut() {
+            v.retain(|&x| x != label);
+        }
+    }
+
+    let product = result
+        .iter()
+        .filter_map(|(k, &v)| {
+            if k.starts_with("departure") {
+                Some(notes.your_ticket[v as usize])
+            } else {
+                None
+            }
+        })
+        .product::<i64>();
+    Some(product)
+}
+
+static FIELDS: [&str; 20] = [
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+
+/// Notes with `size` nearby tickets. Values come in bands of 100, starting
+/// at 100, and the field listed `r`th accepts bands `r` and up, so a column
+/// with a value from band `r` could be any of the first `r + 1` fields and
+/// the fields can be worked out one at a time. Tickets only use the first 60
+/// values of each band, leaving room for the gap in each rule. A quarter of
+/// the nearby tickets have one value outside every rule.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let band = |b: usize| 100 + 100 * b as i64;
+    let max = band(FIELDS.len()) - 1;
+    let mut input = String::new();
+    for (r, field) in FIELDS.iter().enumerate() {
+        let gap = band(rng.range(r as i64, FIELDS.len() as i64 - 1) as usize) + rng.range(60, 95);
+        input += &format!(
+            "{}: {}-{} or {}-{}\n",
+            field,
+            band(r),
+            gap - 1,
+            gap + 3,
+            max
+        );
+    }
+
+    let mut columns: Vec<usize> = (0..FIELDS.len()).collect();
+    rng.shuffle(&mut columns);
+    let ticket = |rng: &mut Rng, lowest: bool| -> Vec<i64> {
+        columns
+            .iter()
+            .map(|&r| {
+                let b = if lowest {
+                    r
+                } else {
+                    rng.range(r as i64, FIELDS.len() as i64 - 1) as usize
+                };
+                band(b) + rng.range(0, 59)
+            })
+            .collect()
+    };
+    let format = |ticket: &[i64]| {
+        ticket
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    // Your ticket is the one with the lowest band in every column.
+    let yours = ticket(rng, true);
+    let departures: i64 = columns
+        .iter()
+        .zip(yours.iter())
+        .filter(|(&r, _)| FIELDS[r].starts_with("departure"))
+        .map(|(_, x)| x)
+        .product();
+    input += &format!("\nyour ticket:\n{}\n\nnearby tickets:\n", format(&yours));
+    let mut error_rate = 0;
+    for _ in 0..size {
+        let mut nearby = ticket(rng, false);
+        if rng.chance(0.25) {
+            let invalid = if rng.chance(0.5) {
+                rng.range(0, 99)
+            } else {
+                rng.range(max + 1, max + 100)
+            };
+            nearby[rng.index(FIELDS.len())] = invalid;
+            error_rate += invalid;
+        }
+        input += &format!("{}\n", format(&nearby));
+    }
+    Generated::new(input).part1(error_rate).part2(departures)
+}
+
+static EXAMPLE: &str = r#"class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12"#;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = Notes;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, notes: &Self::Parsed) -> Answer {
+        ticket_scan_error_rate(notes).into()
+    }
+
+    fn part2(&self, notes: &Self::Parsed) -> Answer {
+        departure_product(notes).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(71)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let notes = parse(EXAMPLE).unwrap();
+        let error_rate = ticket_scan_error_rate(&notes);
+        assert_eq!(error_rate, 71);
+
+        let filtered = p2_notes_filter(&notes);
+        let filter_truth: Vec<Vec<i64>> = vec![vec![7, 3, 47]];
+        assert_eq!(filtered.nearby_tickets, filter_truth);
+    }
+
+    #[test]
+    fn test_unresolved_fields() {
+        let notes = parse(
+            "departure a: 1-5 or 7-9\nclass: 1-5 or 7-9\n\n\
+             your ticket:\n1,2\n\n\
+             nearby tickets:\n3,4\n",
+        )
+        .unwrap();
+        assert_eq!(departure_product(&notes), None);
+    }
+}
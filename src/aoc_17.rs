@@ -0,0 +1,72 @@
+use crate::automaton::{moore_neighborhood, Automaton, Rule};
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+
+fn parse(input: &str) -> Result<Grid<char>> {
+    Grid::parse(input, |c| if c == '.' || c == '#' { Some(c) } else { None })
+}
+
+fn active_after_6_p2(grid: &Grid<char>) -> usize {
+    trace!("initial grid:\n{}", grid);
+    let mut cubes = Automaton::new(moore_neighborhood(), Rule::new(&[3], &[2, 3]));
+    for ((x, y), &c) in grid.positions() {
+        cubes.set((x as i64, y as i64, 0, 0), c == '#');
+    }
+    cubes.run(6);
+    cubes.live_count()
+}
+
+/// A square starting slice with about `size` cubes in it, around a third of
+/// them active. There's no shortcut to how it grows, so no answers.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let side = (1..).find(|n| n * n >= size.max(1)).unwrap();
+    let mut input = String::new();
+    for _ in 0..side {
+        input.extend((0..side).map(|_| if rng.chance(0.35) { '#' } else { '.' }));
+        input.push('\n');
+    }
+    Generated::new(input)
+}
+
+static EXAMPLE: &str = r#".#.
+..#
+###"#;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, _grid: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(&self, grid: &Self::Parsed) -> Answer {
+        active_after_6_p2(grid).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part2(848)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let grid = parse(EXAMPLE).unwrap();
+        assert_eq!(active_after_6_p2(&grid), 848);
+    }
+}
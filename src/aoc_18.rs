@@ -0,0 +1,366 @@
+use crate::error::{parse_lines, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+
+fn unexpected(input: &str, i: usize, c: char) -> AocError {
+    AocError::at(input, &input[i..], ErrorKind::UnexpectedChar(c))
+}
+
+/// Runs `f` on the group whose `(` is at `input[i]`, returning its value and
+/// the offset of its `)` from `i + 1`.
+fn parse_group<T>(input: &str, i: usize, f: fn(&str) -> Result<(T, usize)>) -> Result<(T, usize)> {
+    let sub_slice = &input[i + 1..];
+    let (val, new_i) = f(sub_slice).map_err(|e| e.within(input, sub_slice))?;
+    if new_i == sub_slice.len() {
+        return Err(AocError::at_end(input, ErrorKind::Missing("`)`")));
+    }
+    Ok((val, new_i))
+}
+
+fn parse_impl(input: &str) -> Result<(usize, usize)> {
+    let mut left_operand: usize = 0;
+    let mut op: Option<char> = Some('+');
+    let mut ci_iter = input.char_indices();
+
+    while let Some((i, c)) = ci_iter.next() {
+        match c {
+            ' ' => (),
+            '0'..='9' => {
+                match op {
+                    Some('+') => {
+                        left_operand += c.to_digit(10).unwrap() as usize;
+                    }
+                    Some('*') => {
+                        left_operand *= c.to_digit(10).unwrap() as usize;
+                    }
+                    _ => {
+                        return Err(unexpected(input, i, c));
+                    }
+                }
+                op = None;
+            }
+            '+' | '*' => {
+                if op.is_some() {
+                    return Err(unexpected(input, i, c));
+                }
+                op = Some(c);
+            }
+            '(' => {
+                // slice and recurse
+                let (val, new_i) = parse_group(input, i, parse_impl)?;
+                ci_iter.nth(new_i);
+                match op {
+                    Some('+') => {
+                        left_operand += val;
+                    }
+                    Some('*') => {
+                        left_operand *= val;
+                    }
+                    _ => {
+                        return Err(unexpected(input, i, c));
+                    }
+                }
+                op = None;
+            }
+            ')' => {
+                if op.is_some() {
+                    return Err(AocError::at(
+                        input,
+                        &input[i..],
+                        ErrorKind::Missing("operand"),
+                    ));
+                }
+                return Ok((left_operand, i));
+            }
+
+            _ => {
+                return Err(unexpected(input, i, c));
+            }
+        };
+    }
+
+    if op.is_some() {
+        return Err(AocError::at_end(input, ErrorKind::Missing("operand")));
+    }
+    Ok((left_operand, input.len()))
+}
+
+fn parse(input: &str) -> Result<usize> {
+    let (val, end) = parse_impl(input)?;
+    if end != input.len() {
+        return Err(unexpected(input, end, ')'));
+    }
+    trace!("{} = {}", input, val);
+    Ok(val)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Sym {
+    Op { op: char },
+    Val { val: usize },
+}
+
+fn eval_flat_expr_p2(expr: &[Sym]) -> usize {
+    let add_count = expr
+        .iter()
+        .map(|x| match x {
+            Sym::Op { op } if *op == '+' => 1,
+            _ => 0,
+        })
+        .sum::<usize>();
+
+    let mut expr_so_far = expr.to_vec();
+
+    for _ in 0..add_count {
+        let (add_idx, _) = expr_so_far
+            .iter()
+            .enumerate()
+            .find(|&(_, x)| *x == Sym::Op { op: '+' })
+            .unwrap();
+
+        let val0 = match expr_so_far[add_idx - 1] {
+            Sym::Val { val } => Some(val),
+            _ => None,
+        }
+        .unwrap();
+        let val1 = match expr_so_far[add_idx + 1] {
+            Sym::Val { val } => Some(val),
+            _ => None,
+        }
+        .unwrap();
+
+        let mut new_vec = expr_so_far.drain(0..add_idx - 1).collect::<Vec<_>>();
+
+        new_vec.push(Sym::Val { val: val0 + val1 });
+        new_vec.extend(expr_so_far.drain(3..).collect::<Vec<_>>());
+        expr_so_far = new_vec;
+    }
+
+    let res = expr_so_far
+        .iter()
+        .filter_map(|x| match x {
+            Sym::Val { val } => Some(val),
+            _ => None,
+        })
+        .product::<usize>();
+    res
+}
+
+fn parse_impl2_p2(input: &str) -> Result<(Vec<Sym>, usize)> {
+    let mut ci_iter = input.char_indices();
+    let mut sym_stack: Vec<Sym> = Vec::new();
+    while let Some((i, c)) = ci_iter.next() {
+        let want_operand = !matches!(sym_stack.last(), Some(Sym::Val { .. }));
+        match c {
+            ' ' => (),
+            '0'..='9' if want_operand => {
+                sym_stack.push(Sym::Val {
+                    val: c.to_digit(10).unwrap() as usize,
+                });
+            }
+            '+' | '*' if !want_operand => {
+                sym_stack.push(Sym::Op { op: c });
+            }
+            '(' if want_operand => {
+                // slice and recurse
+                let (sub_expr, new_i) = parse_group(input, i, parse_impl2_p2)?;
+                let sub_expr_val = eval_flat_expr_p2(&sub_expr);
+                sym_stack.push(Sym::Val { val: sub_expr_val });
+
+                ci_iter.nth(new_i);
+            }
+            ')' if want_operand => {
+                return Err(AocError::at(
+                    input,
+                    &input[i..],
+                    ErrorKind::Missing("operand"),
+                ));
+            }
+            ')' => {
+                return Ok((sym_stack, i));
+            }
+
+            _ => {
+                return Err(unexpected(input, i, c));
+            }
+        };
+    }
+
+    if !matches!(sym_stack.last(), Some(Sym::Val { .. })) {
+        return Err(AocError::at_end(input, ErrorKind::Missing("operand")));
+    }
+    Ok((sym_stack, input.len()))
+}
+
+fn parse_p2(input: &str) -> Result<usize> {
+    let (flat_expr, end) = parse_impl2_p2(input)?;
+    if end != input.len() {
+        return Err(unexpected(input, end, ')'));
+    }
+    let val = eval_flat_expr_p2(&flat_expr);
+    trace!("{} = {}", input, val);
+    Ok(val)
+}
+
+/// A random expression, nested at most `depth` groups deep, along with its
+/// value under the part 1 and part 2 rules.
+fn generate_expr(rng: &mut Rng, depth: usize) -> (String, usize, usize) {
+    let mut text = String::new();
+    // Part 1 folds left to right. Part 2 adds up runs of `+` and multiplies
+    // the runs together. Deep products get huge, so both saturate and the
+    // caller throws away anything too big.
+    let (mut left_to_right, mut product, mut sum) = (0usize, 1usize, 0usize);
+    for i in 0..rng.range(2, 5) {
+        let op = if i == 0 {
+            '+'
+        } else {
+            *rng.choose(&['+', '*'])
+        };
+        if i > 0 {
+            text += &format!(" {} ", op);
+        }
+        let (operand, value1, value2) = if depth > 0 && rng.chance(0.3) {
+            let (inner, value1, value2) = generate_expr(rng, depth - 1);
+            (format!("({})", inner), value1, value2)
+        } else {
+            let digit = rng.range(1, 9) as usize;
+            (digit.to_string(), digit, digit)
+        };
+        text += &operand;
+        if op == '+' {
+            left_to_right = left_to_right.saturating_add(value1);
+            sum = sum.saturating_add(value2);
+        } else {
+            left_to_right = left_to_right.saturating_mul(value1);
+            product = product.saturating_mul(sum);
+            sum = value2;
+        }
+    }
+    (text, left_to_right, product.saturating_mul(sum))
+}
+
+/// `size` random expressions, up to three groups deep, whose values stay
+/// under 10^12 like the real inputs.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let (mut total1, mut total2) = (0, 0);
+    for _ in 0..size {
+        let (expr, value1, value2) = loop {
+            let expr = generate_expr(rng, 3);
+            if expr.1.max(expr.2) < 1_000_000_000_000 {
+                break expr;
+            }
+        };
+        input += &expr;
+        input.push('\n');
+        total1 += value1;
+        total2 += value2;
+    }
+    Generated::new(input).part1(total1).part2(total2)
+}
+
+static EXAMPLE: &str = r#"1 + 2 * 3 + 4 * 5 + 6
+1 + (2 * 3) + (4 * (5 + 6))
+2 * 3 + (4 * 5)
+5 + (8 * 3 + 9 + 3 * 4 * 3)
+5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))
+((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"#;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        // Both parts accept the same grammar, so evaluating with the part 1
+        // rules is enough to reject malformed lines up front.
+        parse_lines(input, |l| parse(l).map(|_| l.to_string()))
+    }
+
+    fn part1(&self, lines: &Self::Parsed) -> Answer {
+        lines
+            .iter()
+            .map(|l| parse(l).unwrap())
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(&self, lines: &Self::Parsed) -> Answer {
+        lines
+            .iter()
+            .map(|l| parse_p2(l).unwrap())
+            .sum::<usize>()
+            .into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(26457).part2(694173)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p1() {
+        assert_eq!(parse("1 + 2 * 3 + 4 * 5 + 6").unwrap(), 71);
+        assert_eq!(parse("1 + (2 * 3) + (4 * (5 + 6))").unwrap(), 51);
+        assert_eq!(parse("2 * 3 + (4 * 5)").unwrap(), 26);
+        assert_eq!(parse("5 + (8 * 3 + 9 + 3 * 4 * 3)").unwrap(), 437);
+        assert_eq!(
+            parse("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))").unwrap(),
+            12240
+        );
+        assert_eq!(
+            parse("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2").unwrap(),
+            13632
+        );
+    }
+
+    #[test]
+    fn test_p2() {
+        assert_eq!(parse_p2("1 + 2 * 3 + 4 * 5 + 6").unwrap(), 231);
+        assert_eq!(parse_p2("1 + (2 * 3) + (4 * (5 + 6))").unwrap(), 51);
+        assert_eq!(parse_p2("2 * 3 + (4 * 5)").unwrap(), 46);
+        assert_eq!(parse_p2("5 + (8 * 3 + 9 + 3 * 4 * 3)").unwrap(), 1445);
+        assert_eq!(
+            parse_p2("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))").unwrap(),
+            669060
+        );
+        assert_eq!(
+            parse_p2("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2").unwrap(),
+            23340
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        for f in [parse, parse_p2].iter() {
+            assert_eq!(
+                f("1 + (2 * 3").unwrap_err().to_string(),
+                "line 1, column 11: missing `)`"
+            );
+            assert_eq!(
+                f("1 + 2) * 3").unwrap_err().to_string(),
+                "line 1, column 6: unexpected character `)`"
+            );
+            assert_eq!(
+                f("1 + (2 * x)").unwrap_err().to_string(),
+                "line 1, column 10: unexpected character `x`"
+            );
+            assert_eq!(
+                f("1 + (2 *)").unwrap_err().to_string(),
+                "line 1, column 9: missing operand"
+            );
+            assert_eq!(
+                f("1 2").unwrap_err().to_string(),
+                "line 1, column 3: unexpected character `2`"
+            );
+        }
+    }
+}
@@ -1,42 +1,48 @@
+use crate::error::{parse_lines, parse_num, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
 use regex::Regex;
 use std::collections::HashMap;
 use std::collections::VecDeque;
-use std::env;
-use std::fs;
 
 #[derive(Debug, Clone)]
-enum Rule {
+pub enum Rule {
     Char { c: char },
     Other { r: Vec<Vec<usize>> },
 }
 
-fn parse_rule_ln(input: &str) -> (usize, Rule) {
-    let col_pos = input.find(':').unwrap();
+fn parse_rule_ln(input: &str) -> Result<(usize, Rule)> {
+    let col_pos = input
+        .find(':')
+        .ok_or_else(|| AocError::at(input, &input[input.len()..], ErrorKind::Missing("`:`")))?;
     let idx_slice = &input[..col_pos];
-    let idx = idx_slice.parse::<usize>().unwrap();
+    let idx = parse_num(input, idx_slice)?;
 
     let rem_slice = &input[col_pos + 1..];
     let maybe_quote = rem_slice.find('\"');
     let r = match maybe_quote {
         Some(_) => Rule::Char {
-            c: rem_slice.chars().find(|x| x.is_ascii_alphabetic()).unwrap(),
+            c: rem_slice
+                .chars()
+                .find(|x| x.is_ascii_alphabetic())
+                .ok_or_else(|| AocError::at(input, rem_slice, ErrorKind::Missing("letter")))?,
         },
         None => Rule::Other {
             r: rem_slice
                 .split('|')
                 .map(|sub| {
                     sub.split_whitespace()
-                        .map(|x| x.parse::<usize>().unwrap())
-                        .collect::<Vec<_>>()
+                        .map(|x| parse_num(input, x))
+                        .collect::<Result<Vec<_>>>()
                 })
-                .collect::<Vec<_>>(),
+                .collect::<Result<Vec<_>>>()?,
         },
     };
 
-    (idx, r)
+    Ok((idx, r))
 }
 
-fn to_regex(input: &Vec<(usize, Rule)>) -> HashMap<usize, String> {
+fn to_regex(input: &[(usize, Rule)]) -> HashMap<usize, String> {
     let mut queue: VecDeque<usize> = VecDeque::new();
     let mut regexs: HashMap<usize, String> = HashMap::new();
 
@@ -44,16 +50,16 @@ fn to_regex(input: &Vec<(usize, Rule)>) -> HashMap<usize, String> {
 
     while !queue.is_empty() {
         let cur = queue.pop_back().unwrap();
-        println!("{}", cur);
+        trace!("visiting rule {}", cur);
         if regexs.contains_key(&cur) {
-            println!("skippingrule: {}", cur);
+            trace!("skipping rule {}", cur);
             continue;
         }
         let (rule_idx, input_rule) = &input[cur];
         assert_eq!(*rule_idx, cur);
         match input_rule {
             Rule::Char { c } => {
-                println!("base_rule {} {} {}", cur, *rule_idx, c);
+                trace!("base rule {} {} {}", cur, *rule_idx, c);
                 regexs.insert(*rule_idx, c.to_string());
             }
             Rule::Other { r } => {
@@ -61,22 +67,21 @@ fn to_regex(input: &Vec<(usize, Rule)>) -> HashMap<usize, String> {
                 let mut children: Vec<usize> = Vec::new();
                 if *rule_idx == 0 {
                     if !(regexs.contains_key(&42)) {
-                        println!("missing rule: {}", 42);
+                        trace!("missing rule {}", 42);
                         satisfied = false;
                         children.push(42);
                     }
                     if !(regexs.contains_key(&31)) {
-                        println!("missing rule: {}", 31);
+                        trace!("missing rule {}", 31);
                         satisfied = false;
                         children.push(31);
                     }
-                } else if *rule_idx == 8 {
-                } else if *rule_idx == 11 {
+                } else if *rule_idx == 8 || *rule_idx == 11 {
                 } else {
                     for group in r.iter() {
                         for idx in group.iter() {
-                            if !(regexs.contains_key(&idx)) {
-                                println!("gmissing rule: {}", idx);
+                            if !(regexs.contains_key(idx)) {
+                                trace!("missing rule {}", idx);
                                 satisfied = false;
                                 children.push(*idx);
                             }
@@ -94,7 +99,7 @@ fn to_regex(input: &Vec<(usize, Rule)>) -> HashMap<usize, String> {
                         regex_str.push_str(&regexs[&42]);
                         regex_str.push_str(")+");
                         regex_str.push_str("(?:");
-                        for i in 1..5 {
+                        for i in 1..6 {
                             for _ in 0..i {
                                 regex_str.push_str(&regexs[&42]);
                             }
@@ -150,7 +155,7 @@ fn to_regex(input: &Vec<(usize, Rule)>) -> HashMap<usize, String> {
                                 .collect::<String>(),
                         );
 
-                        while let Some(g) = group_itr.next() {
+                        for g in group_itr {
                             regex_str.push('|');
                             regex_str.push_str(
                                 &g.iter().map(|ri| regexs[ri].as_str()).collect::<String>(),
@@ -165,18 +170,13 @@ fn to_regex(input: &Vec<(usize, Rule)>) -> HashMap<usize, String> {
     }
 
     for (i, r) in regexs.iter() {
-        println!("{}: {}", i, r.as_str());
+        debug!("regex {}: {}", i, r.as_str());
     }
 
     regexs
 }
-fn match_iterative(
-    input: &str,
-    target_rule: usize,
-    part2: bool,
-    rules: &Vec<(usize, Rule)>,
-) -> bool {
-    println!("matching: {}", input);
+fn match_iterative(input: &str, target_rule: usize, part2: bool, rules: &[(usize, Rule)]) -> bool {
+    debug!("matching: {}", input);
     let (matched, advanced) = match_iterative_impl(input, target_rule, part2, rules);
     matched && advanced == input.len()
 }
@@ -185,7 +185,7 @@ fn match_iterative_impl(
     input: &str,
     target_rule: usize,
     part2: bool,
-    rules: &Vec<(usize, Rule)>,
+    rules: &[(usize, Rule)],
 ) -> (bool, usize) {
     if input.is_empty() {
         return (false, 0);
@@ -198,7 +198,7 @@ fn match_iterative_impl(
     while !stack.is_empty() {
         let (offset, rule_idx) = stack.pop_back().unwrap();
 
-        println!("{} {} {}", offset, rule_idx, input.len());
+        trace!("{} {} {}", offset, rule_idx, input.len());
 
         if map.contains_key(&(offset, rule_idx)) {
             // println!("skipping");
@@ -246,12 +246,12 @@ fn match_iterative_impl(
                     }
 
                     if group_matched {
-                        println!("rule 8 matched");
+                        trace!("rule 8 matched");
                         matched = Some(last_offset);
                     }
 
                     if !group_children_visited {
-                        println!("rule 8 needs children");
+                        trace!("rule 8 needs children");
                         all_visited = false;
                     }
                 } else if rule_idx == 11 && part2 {
@@ -303,12 +303,12 @@ fn match_iterative_impl(
                     }
 
                     if group_31_matched >= 1 && (group_42_matched == group_31_matched) {
-                        println!("rule 11 matched");
+                        trace!("rule 11 matched");
                         matched = Some(last_offset);
                     }
 
                     if !group_children_visited {
-                        println!("rule 11 needs children");
+                        trace!("rule 11 needs children");
                         all_visited = false;
                     }
                 } else {
@@ -345,11 +345,11 @@ fn match_iterative_impl(
                     }
                 }
 
-                if matched.is_some() {
+                if let Some(matched_offset) = matched {
                     // println!("marking matched: {} {}", offset, rule_idx);
-                    map.insert((offset, rule_idx), (true, matched.unwrap()));
+                    map.insert((offset, rule_idx), (true, matched_offset));
                 } else if all_visited {
-                    println!("marking unmatched: {} {}", offset, rule_idx);
+                    trace!("marking unmatched: {} {}", offset, rule_idx);
                     map.insert((offset, rule_idx), (false, 0));
                 }
 
@@ -366,60 +366,126 @@ fn match_iterative_impl(
 
     map[&(0, target_rule)]
 }
-fn match_recursive(input: &str, target_rule: usize, rules: &Vec<(usize, Rule)>) -> bool {
-    let (matched, advanced) = match_recursive_impl(input, target_rule, rules);
-    matched && advanced == input.len()
+/// Whether all of `input` matches `target_rule`. Unlike `match_iterative`
+/// this backtracks into every way a rule can match, so it also copes with the
+/// looping part 2 rules.
+#[allow(dead_code)]
+fn match_recursive(input: &str, target_rule: usize, rules: &[(usize, Rule)]) -> bool {
+    match_recursive_impl(input, 0, target_rule, rules).contains(&input.len())
 }
 
+/// Every offset that a match of `target_rule` starting at `offset` can end
+/// at.
+#[allow(dead_code)]
 fn match_recursive_impl(
     input: &str,
+    offset: usize,
     target_rule: usize,
-    rules: &Vec<(usize, Rule)>,
-) -> (bool, usize) {
-    if input.is_empty() {
-        return (false, 0);
+    rules: &[(usize, Rule)],
+) -> Vec<usize> {
+    if offset >= input.len() {
+        return Vec::new();
     }
-    let mut ci = input.char_indices();
     let (_idx, rule) = &rules[target_rule];
     match rule {
         Rule::Char { c } => {
-            let (_i, rc) = ci.next().unwrap();
-            if rc == *c {
-                return (true, 1);
+            if input[offset..].starts_with(*c) {
+                vec![offset + c.len_utf8()]
             } else {
-                return (false, 0);
+                Vec::new()
             }
         }
         Rule::Other { r } => {
-            let mut advance_total: usize = 0;
-            let matches = r.iter().any(|group| {
-                advance_total = 0;
-                group.iter().all(|rule| {
-                    let (matched, advance) =
-                        match_recursive_impl(&input[advance_total..], *rule, &rules);
-                    advance_total += advance;
-                    matched
-                })
-            });
-            return (matches, if matches { advance_total } else { 0 });
+            let mut ends: Vec<usize> = Vec::new();
+            for group in r.iter() {
+                let mut offsets = vec![offset];
+                for rule in group.iter() {
+                    offsets = offsets
+                        .iter()
+                        .flat_map(|&o| match_recursive_impl(input, o, *rule, rules))
+                        .collect();
+                }
+                ends.extend(offsets);
+            }
+            ends.sort_unstable();
+            ends.dedup();
+            ends
         }
     }
 }
 
-fn parse(input: &str) -> (Vec<(usize, Rule)>, Vec<&str>) {
+type Rules = Vec<(usize, Rule)>;
+
+fn parse(input: &str) -> Result<(Rules, Vec<&str>)> {
     let mut iter = input.split("\n\n");
-    let mut rules = iter
-        .next()
-        .unwrap()
-        .lines()
-        .map(|l| parse_rule_ln(l))
-        .collect::<Vec<_>>();
-    rules.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
-    let msgs = iter.next().unwrap().lines().collect::<Vec<&str>>();
-    (rules, msgs)
+    let rules_section = iter.next().unwrap();
+    let mut lines = parse_lines(rules_section, |l| parse_rule_ln(l).map(|r| (l, r)))
+        .map_err(|e| e.within(input, rules_section))?;
+    lines.sort_by(|a, b| (a.1).0.partial_cmp(&(b.1).0).unwrap());
+
+    // Rules are looked up by position, so fill any ids the input skips with
+    // rules that can't match anything.
+    let mut rules: Rules = Vec::new();
+    for (l, (idx, rule)) in lines.iter() {
+        if rules.len() > *idx {
+            return Err(AocError::at(
+                input,
+                l,
+                ErrorKind::Invalid(format!("rule {} is defined twice", idx)),
+            ));
+        }
+        while rules.len() < *idx {
+            rules.push((rules.len(), Rule::Other { r: vec![] }));
+        }
+        rules.push((*idx, rule.clone()));
+    }
+
+    let defined = |idx: usize| lines.iter().any(|(_, r)| r.0 == idx);
+    if !defined(0) {
+        return Err(AocError::at(
+            input,
+            rules_section,
+            ErrorKind::Missing("rule 0"),
+        ));
+    }
+    for (l, (_, rule)) in lines.iter() {
+        if let Rule::Other { r } = rule {
+            if let Some(&missing) = r.iter().flatten().find(|&&x| !defined(x)) {
+                let token = l[l.find(':').unwrap()..]
+                    .split(&[' ', '|'][..])
+                    .find(|&x| x.parse() == Ok(missing))
+                    .unwrap();
+                return Err(AocError::at(
+                    input,
+                    token,
+                    ErrorKind::Unknown {
+                        what: "rule",
+                        token: token.to_string(),
+                    },
+                ));
+            }
+        }
+    }
+
+    let msgs = match iter.next() {
+        Some(x) => x.lines().collect::<Vec<&str>>(),
+        None => Vec::new(),
+    };
+    Ok((rules, msgs))
 }
 
-fn part2_adjustment(rules: &Vec<(usize, Rule)>) -> Vec<(usize, Rule)> {
+/// Swaps rules 8 and 11 for their looping part 2 versions, or `None` if the
+/// grammar doesn't define them and the rules 42 and 31 they loop over.
+fn part2_adjustment(rules: &[(usize, Rule)]) -> Option<Vec<(usize, Rule)>> {
+    // Ids the input skips are filled with rules without any alternatives.
+    let defined = |idx: usize| match rules.get(idx) {
+        Some((_, Rule::Other { r })) => !r.is_empty(),
+        Some(_) => true,
+        None => false,
+    };
+    if ![8, 11, 42, 31].iter().all(|&idx| defined(idx)) {
+        return None;
+    }
     let mut new_rules = rules.to_vec();
     {
         let (idx, rule) = &mut new_rules[8];
@@ -436,7 +502,7 @@ fn part2_adjustment(rules: &Vec<(usize, Rule)>) -> Vec<(usize, Rule)> {
         };
     }
 
-    new_rules
+    Some(new_rules)
 }
 
 fn match_exact(input: &str, re: &Regex) -> bool {
@@ -446,14 +512,145 @@ fn match_exact(input: &str, re: &Regex) -> bool {
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        panic!("We need exactly one arguemnt, got: {}", args.len() - 1);
+fn count_p1(rules: &[(usize, Rule)], msgs: &[String]) -> usize {
+    debug!("rules: {:?}", rules);
+    msgs.iter()
+        .filter(|&m| match_iterative(m, 0, false, rules))
+        .count()
+}
+
+fn count_p2(rules: &[(usize, Rule)], msgs: &[String]) -> Option<usize> {
+    let rules2 = part2_adjustment(rules)?;
+    let r = to_regex(&rules2);
+    debug!("adjusted rules: {:?}", rules2);
+    let re = Regex::new(&r[&0]).unwrap();
+    Some(msgs.iter().filter(|&m| match_exact(m, &re)).count())
+
+    // msgs.iter()
+    //     .filter(|&m| match_iterative(m, 0, true, &rules2))
+    //     .count()
+}
+
+/// Adds rules matching exactly the strings in `suffixes`, which all have the
+/// same non-zero length, as a trie with one rule per node. Returns the id of
+/// the root rule, or `id` if it's given.
+fn add_trie(
+    rules: &mut Vec<String>,
+    letters: [usize; 2],
+    suffixes: &[String],
+    id: Option<usize>,
+) -> usize {
+    let id = id.unwrap_or_else(|| {
+        rules.push(String::new());
+        rules.len() - 1
+    });
+    let mut alternatives = Vec::new();
+    for (&letter, c) in letters.iter().zip(['a', 'b'].iter()) {
+        let rest: Vec<String> = suffixes
+            .iter()
+            .filter(|s| s.starts_with(*c))
+            .map(|s| s[1..].to_string())
+            .collect();
+        if rest.is_empty() {
+            continue;
+        }
+        if rest[0].is_empty() {
+            alternatives.push(letter.to_string());
+        } else {
+            alternatives.push(format!(
+                "{} {}",
+                letter,
+                add_trie(rules, letters, &rest, None)
+            ));
+        }
+    }
+    rules[id] = alternatives.join(" | ");
+    id
+}
+
+/// The usual `0: 8 11` grammar where rules 42 and 31 each match fixed-length
+/// chunks, splitting every chunk of that length between them. A message's
+/// chunks then decide which parts it matches. There are `size` messages,
+/// some of them too short or long to split into chunks at all.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let len = rng.range(3, 6) as usize;
+    let mut chunks: Vec<String> = (0..1 << len)
+        .map(|i: usize| {
+            (0..len)
+                .rev()
+                .map(|b| if i >> b & 1 == 1 { 'b' } else { 'a' })
+                .collect()
+        })
+        .collect();
+    rng.shuffle(&mut chunks);
+    let split = rng.range(1, chunks.len() as i64 - 1) as usize;
+    let (chunks42, chunks31) = chunks.split_at(split);
+
+    // Fixed ids for the rules everything hangs off, with the rest after them.
+    let mut rules = vec![String::new(); 43];
+    rules[0] = "8 11".to_string();
+    rules[8] = "42".to_string();
+    rules[11] = "42 31".to_string();
+    rules.push("\"a\"".to_string());
+    rules.push("\"b\"".to_string());
+    let letters = [rules.len() - 2, rules.len() - 1];
+    add_trie(&mut rules, letters, chunks42, Some(42));
+    add_trie(&mut rules, letters, chunks31, Some(31));
+    let mut lines: Vec<String> = rules
+        .iter()
+        .enumerate()
+        .filter(|(_, rule)| !rule.is_empty())
+        .map(|(id, rule)| format!("{}: {}\n", id, rule))
+        .collect();
+    rng.shuffle(&mut lines);
+
+    let mut input = lines.concat() + "\n";
+    let (mut matches1, mut matches2) = (0, 0);
+    for _ in 0..size {
+        // Part 2 matches some 42s followed by fewer 31s, but only handles up
+        // to six 31s.
+        let (count42, count31) = match rng.index(3) {
+            0 => (2, 1),
+            1 => {
+                let count42 = rng.range(2, 7);
+                (count42, rng.range(1, (count42 - 1).min(6)))
+            }
+            _ => (rng.range(0, 5), rng.range(0, 5)),
+        };
+        let mut message = String::new();
+        for _ in 0..count42 {
+            message += rng.choose(chunks42).as_str();
+        }
+        for _ in 0..count31 {
+            message += rng.choose(chunks31).as_str();
+        }
+        if message.is_empty() || rng.chance(0.1) {
+            let extra = rng.range(1, len as i64 - 1) as usize;
+            message += &rng.string("ab", extra);
+        } else {
+            matches1 += (count42 == 2 && count31 == 1) as usize;
+            matches2 += (count31 >= 1 && count31 < count42 && count31 <= 6) as usize;
+        }
+        input += &message;
+        input.push('\n');
     }
+    Generated::new(input).part1(matches1).part2(matches2)
+}
+
+static EXAMPLE_1: &str = r#"0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: "a"
+5: "b"
 
-    let file_contents = fs::read_to_string(&args[1]).expect("couldn't read the file");
-    let test_str = r#"42: 9 14 | 10 1
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb"#;
+
+static EXAMPLE: &str = r#"42: 9 14 | 10 1
 9: 14 27 | 1 26
 10: 23 14 | 28 1
 1: "a"
@@ -500,31 +697,107 @@ aaaabbaaaabbaaa
 aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
 babaaabbbaaabaababbaabababaaab
 aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba"#;
-    let (rules, msgs) = parse(&file_contents);
-    {
-        println!("{:?}", rules);
 
-        let msg_0_count = msgs
-            .iter()
-            .filter(|&m| match_iterative(m, 0, false, &rules))
-            .count();
-        println!("p1: {}", msg_0_count);
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = (Rules, Vec<String>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        let (rules, msgs) = parse(input)?;
+        Ok((rules, msgs.iter().map(|m| m.to_string()).collect()))
     }
 
-    {
-        let rules2 = part2_adjustment(&rules);
-        let r = to_regex(&rules2);
-        println!("rules{:?}", rules2);
-        let msg_0_count = msgs
-            .iter()
-            .filter(|&m| match_exact(m, &Regex::new(&r[&0]).unwrap()))
-            .count();
-        println!("p2: {}", msg_0_count);
-
-        // let msg_0_count = msgs
-        //     .iter()
-        //     .filter(|&m| match_iterative(m, 0, true, &rules2))
-        //     .count();
-        // println!("p2: {}", msg_0_count);
+    fn part1(&self, (rules, msgs): &Self::Parsed) -> Answer {
+        count_p1(rules, msgs).into()
+    }
+
+    fn part2(&self, (rules, msgs): &Self::Parsed) -> Answer {
+        count_p2(rules, msgs).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(EXAMPLE_1).part1(2),
+            Example::new(EXAMPLE).part1(3).part2(12),
+        ]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{compare, shrink_string};
+
+    /// A grammar from the generator, as its rules section, and one message,
+    /// sometimes with a letter changed.
+    fn random_case(rng: &mut Rng) -> (String, String) {
+        let input = generate(rng, 1).input;
+        let split = input.find("\n\n").unwrap() + 2;
+        let mut message: Vec<char> = input[split..].trim_end().chars().collect();
+        if !message.is_empty() && rng.chance(0.3) {
+            let i = rng.index(message.len());
+            message[i] = if message[i] == 'a' { 'b' } else { 'a' };
+        }
+        (input[..split].to_string(), message.into_iter().collect())
+    }
+
+    fn shrink_message((rules, message): &(String, String)) -> Vec<(String, String)> {
+        shrink_string(message)
+            .into_iter()
+            .map(|m| (rules.clone(), m))
+            .collect()
+    }
+
+    #[test]
+    fn test_differential_p1() {
+        let recursive = |(rules, message): &(String, String)| {
+            match_recursive(message, 0, &parse(rules).unwrap().0)
+        };
+        let iterative = |(rules, message): &(String, String)| {
+            match_iterative(message, 0, false, &parse(rules).unwrap().0)
+        };
+        if let Err(d) = compare(random_case, shrink_message, recursive, iterative) {
+            panic!("match_recursive and match_iterative disagree, {}", d);
+        }
+    }
+
+    #[test]
+    fn test_differential_p2() {
+        let recursive = |(rules, message): &(String, String)| {
+            match_recursive(
+                message,
+                0,
+                &part2_adjustment(&parse(rules).unwrap().0).unwrap(),
+            )
+        };
+        let regex = |(rules, message): &(String, String)| {
+            let regexs = to_regex(&part2_adjustment(&parse(rules).unwrap().0).unwrap());
+            match_exact(message, &Regex::new(&regexs[&0]).unwrap())
+        };
+        if let Err(d) = compare(random_case, shrink_message, recursive, regex) {
+            panic!("match_recursive and the regex disagree, {}", d);
+        }
+    }
+
+    #[test]
+    fn test() {
+        let (rules, msgs) = Day19.parse(EXAMPLE).unwrap();
+        assert_eq!(count_p1(&rules, &msgs), 3);
+        assert_eq!(count_p2(&rules, &msgs), Some(12));
+        let rules2 = part2_adjustment(&rules).unwrap();
+        let recursive = msgs.iter().filter(|m| match_recursive(m, 0, &rules2));
+        assert_eq!(recursive.count(), 12);
+    }
+
+    #[test]
+    fn test_without_loop_rules() {
+        let (rules, msgs) = Day19.parse(EXAMPLE_1).unwrap();
+        assert_eq!(count_p1(&rules, &msgs), 2);
+        assert_eq!(count_p2(&rules, &msgs), None);
     }
 }
@@ -0,0 +1,1038 @@
+use crate::error::{parse_num, required, AocError, ErrorKind, Result};
+use crate::grid::Grid;
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet};
+
+const TILE_WH: usize = 10;
+
+#[derive(Debug, Clone)]
+pub struct Tile {
+    id: usize,
+    top_edge: [char; TILE_WH],
+    bottom_edge: [char; TILE_WH],
+    left_edge: [char; TILE_WH],
+    right_edge: [char; TILE_WH],
+    contents: Grid<char>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Orientation {
+    cw_turns: usize,
+    flip_lr: bool,
+    flip_tb: bool,
+}
+
+fn up_aligning_orientations(edges: [bool; 8]) -> Vec<Orientation> {
+    let mut orientations: Vec<Orientation> = Vec::new();
+    if edges[0] {
+        orientations.push(Orientation {
+            cw_turns: 0,
+            flip_lr: false,
+            flip_tb: false,
+        });
+    }
+    if edges[1] {
+        orientations.push(Orientation {
+            cw_turns: 0,
+            flip_lr: true,
+            flip_tb: false,
+        });
+    }
+    if edges[2] {
+        orientations.push(Orientation {
+            cw_turns: 2,
+            flip_lr: false,
+            flip_tb: false,
+        });
+    }
+    if edges[3] {
+        orientations.push(Orientation {
+            cw_turns: 2,
+            flip_lr: true,
+            flip_tb: false,
+        });
+    }
+    if edges[4] {
+        orientations.push(Orientation {
+            cw_turns: 1,
+            flip_lr: false,
+            flip_tb: false,
+        });
+    }
+    if edges[5] {
+        orientations.push(Orientation {
+            cw_turns: 1,
+            flip_lr: true,
+            flip_tb: false,
+        });
+    }
+    if edges[6] {
+        orientations.push(Orientation {
+            cw_turns: 3,
+            flip_lr: false,
+            flip_tb: false,
+        });
+    }
+    if edges[7] {
+        orientations.push(Orientation {
+            cw_turns: 3,
+            flip_lr: true,
+            flip_tb: false,
+        });
+    }
+
+    orientations
+}
+
+fn left_aligning_orientations(edges: [bool; 8]) -> Vec<Orientation> {
+    let mut orientations: Vec<Orientation> = Vec::new();
+    if edges[0] {
+        orientations.push(Orientation {
+            cw_turns: 3,
+            flip_lr: false,
+            flip_tb: false,
+        });
+    }
+    if edges[1] {
+        orientations.push(Orientation {
+            cw_turns: 3,
+            flip_lr: false,
+            flip_tb: true,
+        });
+    }
+    if edges[2] {
+        orientations.push(Orientation {
+            cw_turns: 1,
+            flip_lr: false,
+            flip_tb: false,
+        });
+    }
+    if edges[3] {
+        orientations.push(Orientation {
+            cw_turns: 1,
+            flip_lr: false,
+            flip_tb: true,
+        });
+    }
+    if edges[4] {
+        orientations.push(Orientation {
+            cw_turns: 0,
+            flip_lr: false,
+            flip_tb: false,
+        });
+    }
+    if edges[5] {
+        orientations.push(Orientation {
+            cw_turns: 0,
+            flip_lr: false,
+            flip_tb: true,
+        });
+    }
+    if edges[6] {
+        orientations.push(Orientation {
+            cw_turns: 2,
+            flip_lr: false,
+            flip_tb: false,
+        });
+    }
+    if edges[7] {
+        orientations.push(Orientation {
+            cw_turns: 2,
+            flip_lr: false,
+            flip_tb: true,
+        });
+    }
+
+    orientations
+}
+
+impl Tile {
+    fn has_edge(&self, edge: &[char; TILE_WH]) -> [bool; 8] {
+        [
+            edge.iter().eq(self.top_edge.iter()),
+            edge.iter().eq(self.top_edge.iter().rev()),
+            edge.iter().eq(self.bottom_edge.iter()),
+            edge.iter().eq(self.bottom_edge.iter().rev()),
+            edge.iter().eq(self.left_edge.iter()),
+            edge.iter().eq(self.left_edge.iter().rev()),
+            edge.iter().eq(self.right_edge.iter()),
+            edge.iter().eq(self.right_edge.iter().rev()),
+        ]
+    }
+
+    fn rotate(&self) -> Tile {
+        Tile {
+            id: self.id,
+            top_edge: self.left_edge,
+            bottom_edge: self.right_edge,
+            left_edge: self.bottom_edge,
+            right_edge: self.top_edge,
+            contents: self.contents.rotate_cw(),
+        }
+    }
+
+    fn flip_lr(&self) -> Tile {
+        let mut t_tmp = self.top_edge;
+        t_tmp.reverse();
+        let mut b_tmp = self.bottom_edge;
+        b_tmp.reverse();
+        let mut l_tmp = self.left_edge;
+        l_tmp.reverse();
+        let mut r_tmp = self.right_edge;
+        r_tmp.reverse();
+        Tile {
+            id: self.id,
+            top_edge: t_tmp,
+            bottom_edge: b_tmp,
+            left_edge: r_tmp,
+            right_edge: l_tmp,
+            contents: self.contents.flip_lr(),
+        }
+    }
+    fn flip_tb(&self) -> Tile {
+        let mut t_tmp = self.top_edge;
+        t_tmp.reverse();
+        let mut b_tmp = self.bottom_edge;
+        b_tmp.reverse();
+        let mut l_tmp = self.left_edge;
+        l_tmp.reverse();
+        let mut r_tmp = self.right_edge;
+        r_tmp.reverse();
+        Tile {
+            id: self.id,
+            top_edge: b_tmp,
+            bottom_edge: t_tmp,
+            left_edge: l_tmp,
+            right_edge: r_tmp,
+            contents: self.contents.flip_ud(),
+        }
+    }
+}
+
+fn parse_tile_contents(chunk: &str, body: &str) -> Result<Grid<char>> {
+    let contents = Grid::parse(body, |c| if c == '.' || c == '#' { Some(c) } else { None })
+        .map_err(|e| e.within(chunk, body))?;
+    if contents.height() != TILE_WH {
+        return Err(AocError::at_end(
+            chunk,
+            ErrorKind::Invalid(format!(
+                "expected {} rows, got {}",
+                TILE_WH,
+                contents.height()
+            )),
+        ));
+    }
+    if contents.width() != TILE_WH {
+        return Err(AocError::at(
+            chunk,
+            body,
+            ErrorKind::Invalid(format!("expected a row of width {}", TILE_WH)),
+        ));
+    }
+    Ok(contents)
+}
+
+fn edge<'a>(cells: impl Iterator<Item = &'a char>) -> [char; TILE_WH] {
+    let mut edge = ['.'; TILE_WH];
+    for (e, &c) in edge.iter_mut().zip(cells) {
+        *e = c;
+    }
+    edge
+}
+
+fn parse_tile(chunk: &str) -> Result<Tile> {
+    let tile_line = required(chunk, chunk.lines().next(), "tile header")?;
+    let tile_slice = tile_line
+        .strip_prefix("Tile ")
+        .and_then(|x| x.strip_suffix(':'))
+        .ok_or_else(|| {
+            AocError::at(
+                chunk,
+                tile_line,
+                ErrorKind::Invalid("expected `Tile <id>:`".to_string()),
+            )
+        })?;
+    let tile = parse_num(chunk, tile_slice)?;
+
+    let body = chunk.get(tile_line.len() + 1..).unwrap_or("");
+    let contents = parse_tile_contents(chunk, body)?;
+
+    // Storage is clockwise in the canonical frame
+    let top_edge = edge(contents.rows().next().unwrap().iter());
+    let bottom_edge = edge(contents.rows().last().unwrap().iter().rev());
+    let left_edge = edge(contents.column(0).collect::<Vec<_>>().into_iter().rev());
+    let right_edge = edge(contents.column(TILE_WH - 1));
+    Ok(Tile {
+        id: tile,
+        top_edge,
+        bottom_edge,
+        left_edge,
+        right_edge,
+        contents,
+    })
+}
+
+fn parse(input: &str) -> Result<Vec<Tile>> {
+    let mut tiles: Vec<Tile> = Vec::new();
+    for chunk in input.split("\n\n").filter(|chunk| !chunk.trim().is_empty()) {
+        let tile = parse_tile(chunk).map_err(|e| e.within(input, chunk))?;
+        if tiles.iter().any(|t| t.id == tile.id) {
+            return Err(AocError::at(
+                input,
+                chunk,
+                ErrorKind::Invalid(format!("tile {} is given twice", tile.id)),
+            ));
+        }
+        tiles.push(tile);
+    }
+
+    let grid_dim = (tiles.len() as f32).sqrt() as usize;
+    if tiles.is_empty() {
+        return Err(AocError::at_end(input, ErrorKind::Missing("tiles")));
+    } else if grid_dim * grid_dim != tiles.len() {
+        return Err(AocError::at_end(
+            input,
+            ErrorKind::Invalid(format!(
+                "expected a square number of tiles, got {}",
+                tiles.len()
+            )),
+        ));
+    }
+    Ok(tiles)
+}
+
+/// Sorts tile ids into corner, edge and middle tiles by how many other
+/// tiles they share an edge with, or `None` if the counts can't make up a
+/// square image.
+fn classify_tiles(tiles: &[Tile]) -> Option<(Vec<usize>, Vec<usize>, Vec<usize>)> {
+    let grid_dim = (tiles.len() as f32).sqrt() as usize;
+    if grid_dim < 2 || grid_dim * grid_dim != tiles.len() {
+        return None;
+    }
+
+    let mut corner_ids: Vec<usize> = Vec::new();
+    let mut edge_ids: Vec<usize> = Vec::new();
+    let mut middle_ids: Vec<usize> = Vec::new();
+    for i in tiles.iter() {
+        let mut match_count = 0;
+        let edges = [i.top_edge, i.bottom_edge, i.left_edge, i.right_edge];
+        for j in tiles.iter() {
+            if i.id == j.id {
+                continue;
+            }
+            for e in edges.iter() {
+                if j.has_edge(e).iter().any(|x| *x) {
+                    match_count += 1;
+                    break;
+                }
+            }
+        }
+        match match_count {
+            2 => corner_ids.push(i.id),
+            3 => edge_ids.push(i.id),
+            4 => middle_ids.push(i.id),
+            _ => {
+                debug!("tile {} matches {} others", i.id, match_count);
+                return None;
+            }
+        }
+    }
+
+    if corner_ids.len() != 4 || edge_ids.len() != 4 * (grid_dim - 2) {
+        debug!(
+            "{} corner and {} edge tiles can't make a square",
+            corner_ids.len(),
+            edge_ids.len()
+        );
+        return None;
+    }
+
+    Some((corner_ids, edge_ids, middle_ids))
+}
+
+/// Assembles the image and counts its rough water, or `None` if the tiles
+/// can't be put together.
+fn align_grid(tiles: &[Tile]) -> Option<usize> {
+    let grid_dim = (tiles.len() as f32).sqrt() as usize;
+
+    let tiles_map = tiles
+        .iter()
+        .map(|t| (t.id, (t.clone())))
+        .collect::<HashMap<_, _>>();
+
+    let (corner_ids, edge_ids, middle_ids) = classify_tiles(tiles)?;
+
+    let next_pt = |(x, y): (usize, usize)| {
+        if y == grid_dim - 1 && x == grid_dim - 1 {
+            None
+        } else if x == grid_dim - 1 {
+            Some((0, y + 1))
+        } else {
+            Some((x + 1, y))
+        }
+    };
+    let is_corner_pt = |xy: (usize, usize)| {
+        let max = grid_dim - 1;
+        match xy {
+            (0, 0) => true,
+            k if (k == (0, max)) => true,
+            k if (k == (max, 0)) => true,
+            k if (k == (max, max)) => true,
+            _ => false,
+        }
+    };
+    let is_edge_pt = |xy: (usize, usize)| {
+        if is_corner_pt(xy) {
+            false
+        } else {
+            let max = grid_dim - 1;
+            match xy {
+                (0, _) => true,
+                (_, 0) => true,
+                k if (k.0 == max) => true,
+                k if (k.1 == max) => true,
+                _ => false,
+            }
+        }
+    };
+
+    let mut assignments: HashMap<(usize, usize), Option<Tile>> = HashMap::new();
+    for y in 0..grid_dim {
+        for x in 0..grid_dim {
+            assignments.insert((x, y), None);
+        }
+    }
+    let mut id_assignments: HashMap<usize, Option<(usize, usize)>> = HashMap::new();
+    for i in tiles.iter() {
+        id_assignments.insert(i.id, None);
+    }
+
+    let mut placed_all = false;
+    let mut search_queue: VecDeque<((usize, usize), Tile)> = VecDeque::new();
+    for ci in corner_ids.iter() {
+        let mut unmatched_edges: [bool; 4] = [false; 4];
+
+        let ci_tile = tiles_map[ci].clone();
+        let edges = [
+            ci_tile.top_edge,
+            ci_tile.bottom_edge,
+            ci_tile.left_edge,
+            ci_tile.right_edge,
+        ];
+        for (i, e) in edges.iter().enumerate() {
+            for j in tiles.iter() {
+                if *ci == j.id {
+                    continue;
+                }
+                let matched = j.has_edge(e).iter().any(|x| *x);
+                if matched {
+                    unmatched_edges[i] = matched;
+                    break;
+                }
+            }
+        }
+
+        for m in unmatched_edges.iter_mut() {
+            *m = !(*m);
+        }
+        let mut orientations: Vec<Orientation> = Vec::new();
+        // 0top 1 bottom 2 left 3 right
+        if unmatched_edges[0] && unmatched_edges[2] {
+            // top and left
+            orientations.push(Orientation {
+                cw_turns: 0,
+                flip_lr: false,
+                flip_tb: false,
+            });
+            orientations.push(Orientation {
+                cw_turns: 3,
+                flip_lr: false,
+                flip_tb: true,
+            });
+        } else if unmatched_edges[0] && unmatched_edges[3] {
+            // top and right
+            orientations.push(Orientation {
+                cw_turns: 0,
+                flip_lr: true,
+                flip_tb: false,
+            });
+            orientations.push(Orientation {
+                cw_turns: 3,
+                flip_lr: false,
+                flip_tb: false,
+            });
+        } else if unmatched_edges[1] && unmatched_edges[2] {
+            //   bottom and left
+            orientations.push(Orientation {
+                cw_turns: 1,
+                flip_lr: false,
+                flip_tb: false,
+            });
+            orientations.push(Orientation {
+                cw_turns: 2,
+                flip_lr: true,
+                flip_tb: false,
+            });
+        } else if unmatched_edges[1] && unmatched_edges[3] {
+            //   bottom and right
+            orientations.push(Orientation {
+                cw_turns: 2,
+                flip_lr: false,
+                flip_tb: false,
+            });
+            orientations.push(Orientation {
+                cw_turns: 1,
+                flip_lr: true,
+                flip_tb: true,
+            });
+        } else {
+            debug!("corner tile {} has opposite edges unmatched", ci);
+            return None;
+        }
+        for o in orientations.iter() {
+            let mut tile = tiles_map[ci].clone();
+            for _ in 0..o.cw_turns {
+                tile = tile.rotate();
+            }
+            if o.flip_lr {
+                tile = tile.flip_lr();
+            }
+            if o.flip_tb {
+                tile = tile.flip_tb();
+            }
+            search_queue.push_front(((0, 0), tile));
+        }
+    }
+
+    while !search_queue.is_empty() {
+        let (xy_cur, tile) = search_queue.pop_back().unwrap();
+
+        // clear everything past us in case we popped from far ahead
+        let mut xy_clear = if xy_cur == (0, 0) { None } else { Some(xy_cur) };
+        while let Some(xy) = xy_clear {
+            let maybe_tile = assignments[&xy].as_ref();
+            if maybe_tile.is_none() {
+                break;
+            }
+            let tile = assignments[&xy].as_ref().unwrap();
+            *id_assignments.get_mut(&tile.id).unwrap() = None;
+            *assignments.get_mut(&xy).unwrap() = None;
+            xy_clear = next_pt(xy);
+        }
+
+        *id_assignments.get_mut(&(tile.id.clone())).unwrap() = Some(xy_cur);
+        *assignments.get_mut(&xy_cur).unwrap() = Some(tile);
+
+        let maybe_xy_next = next_pt(xy_cur);
+
+        if maybe_xy_next.is_none() {
+            debug!("all tiles placed");
+            placed_all = true;
+            break;
+        }
+        let xy_next = maybe_xy_next.unwrap();
+
+        let available_tiles: Vec<usize> = if is_corner_pt(xy_next) {
+            corner_ids
+                .iter()
+                .copied()
+                .filter(|c| id_assignments[c].is_none())
+                .collect::<Vec<_>>()
+        } else if is_edge_pt(xy_next) {
+            edge_ids
+                .iter()
+                .copied()
+                .filter(|c| id_assignments[c].is_none())
+                .collect::<Vec<_>>()
+        } else {
+            middle_ids
+                .iter()
+                .copied()
+                .filter(|c| id_assignments[c].is_none())
+                .collect::<Vec<_>>()
+        };
+
+        let up_constraint = {
+            if xy_next.1 == 0 {
+                None
+            } else {
+                let up_tile = assignments[&(xy_next.0, xy_next.1 - 1)].as_ref().unwrap();
+                let mut be = up_tile.bottom_edge;
+                be.reverse();
+                Some(be)
+            }
+        };
+        let left_constraint = {
+            if xy_next.0 == 0 {
+                None
+            } else {
+                let left_tile = assignments[&(xy_next.0 - 1, xy_next.1)].as_ref().unwrap();
+                let mut re = left_tile.right_edge;
+                re.reverse();
+                Some(re)
+            }
+        };
+
+        for at in available_tiles.iter() {
+            let mut ua_or: Vec<Orientation> = Vec::new();
+            if let Some(uc) = up_constraint {
+                let edge = tiles_map[at].has_edge(&uc);
+                if edge.iter().any(|x| *x) {
+                    ua_or = up_aligning_orientations(edge);
+                } else {
+                    // constraint unfulfilled
+                    continue;
+                }
+            }
+            let mut la_or: Vec<Orientation> = Vec::new();
+            if let Some(lc) = left_constraint {
+                let edge = tiles_map[at].has_edge(&lc);
+                if edge.iter().any(|x| *x) {
+                    la_or = left_aligning_orientations(edge);
+                } else {
+                    // constraint unfulfilled
+                    continue;
+                }
+            }
+
+            for o in ua_or.iter().chain(la_or.iter()) {
+                let mut tile = tiles_map[at].clone();
+                for _ in 0..o.cw_turns {
+                    tile = tile.rotate();
+                }
+                if o.flip_lr {
+                    tile = tile.flip_lr();
+                }
+                if o.flip_tb {
+                    tile = tile.flip_tb();
+                }
+                let mut matches_up = true;
+                let mut matches_left = true;
+                if let Some(uc) = up_constraint {
+                    matches_up = tile.top_edge.iter().eq(uc.iter());
+                }
+                if let Some(lc) = left_constraint {
+                    matches_left = tile.left_edge.iter().eq(lc.iter());
+                }
+
+                if matches_up && matches_left {
+                    search_queue.push_back((xy_next, tile));
+                }
+            }
+        }
+    }
+
+    if !placed_all {
+        debug!("ran out of tiles to place");
+        return None;
+    }
+
+    let inner_dim = TILE_WH - 2;
+    let packed_dim = inner_dim * grid_dim;
+
+    let mut final_grid = Grid::filled(packed_dim, packed_dim, '.');
+
+    for gy in 0..grid_dim {
+        for gx in 0..grid_dim {
+            let tile = assignments[&(gx, gy)].as_ref().unwrap();
+            for ty in 0..inner_dim {
+                for tx in 0..inner_dim {
+                    final_grid[(gx * inner_dim + tx, gy * inner_dim + ty)] =
+                        tile.contents[(tx + 1, ty + 1)];
+                }
+            }
+        }
+    }
+    trace!("image:\n{}", final_grid);
+
+    Some(rough_water(&final_grid))
+}
+
+static MONSTER: &str = "                  # \n#    ##    ##    ###\n #  #  #  #  #  #   ";
+
+fn monster_offsets() -> Vec<(usize, usize)> {
+    MONSTER
+        .lines()
+        .enumerate()
+        .flat_map(|(y, l)| l.match_indices('#').map(move |(x, _)| (x, y)))
+        .collect()
+}
+
+/// `image` turned and flipped every way it can be.
+fn orientations(image: &Grid<char>) -> Vec<Grid<char>> {
+    let mut views = Vec::new();
+    let mut turned = image.clone();
+    for _ in 0..4 {
+        let next = turned.rotate_cw();
+        views.push(turned.flip_lr());
+        views.push(turned);
+        turned = next;
+    }
+    views
+}
+
+/// The top left corners of the sea monsters in `view`, as it's oriented.
+fn find_monsters(view: &Grid<char>) -> Vec<(usize, usize)> {
+    let offsets = monster_offsets();
+    view.positions()
+        .map(|(xy, _)| xy)
+        .filter(|&(x, y)| {
+            offsets
+                .iter()
+                .all(|&(dx, dy)| view.get((x + dx) as i64, (y + dy) as i64) == Some(&'#'))
+        })
+        .collect()
+}
+
+/// How many sea monsters `image` has, in any orientation.
+fn count_monsters(image: &Grid<char>) -> usize {
+    orientations(image)
+        .iter()
+        .map(|view| find_monsters(view).len())
+        .sum()
+}
+
+/// The `#` cells of `image` that aren't part of a sea monster, in whichever
+/// orientation has the monsters. Cells shared by overlapping monsters are
+/// only taken away once.
+fn rough_water(image: &Grid<char>) -> usize {
+    let offsets = monster_offsets();
+    let covered = orientations(image)
+        .iter()
+        .map(|view| {
+            let monsters = find_monsters(view);
+            if !monsters.is_empty() {
+                debug!("found monsters at: {:?}", monsters);
+            }
+            monsters
+                .iter()
+                .flat_map(|&(x, y)| offsets.iter().map(move |&(dx, dy)| (x + dx, y + dy)))
+                .collect::<HashSet<_>>()
+                .len()
+        })
+        .max()
+        .unwrap_or(0);
+    image.iter().filter(|&&c| c == '#').count() - covered
+}
+
+/// A square image for `side` by `side` tiles with some sea monsters in it,
+/// often right up against the edges, and the number of rough water cells.
+fn generate_image(rng: &mut Rng, side: usize) -> (Grid<char>, usize) {
+    let dim = side * (TILE_WH - 2);
+    let offsets = monster_offsets();
+    let (monster_w, monster_h) = (20, 3);
+    loop {
+        let mut image = Grid::filled(dim, dim, '.');
+        for cell in image.positions().map(|(xy, _)| xy).collect::<Vec<_>>() {
+            if rng.chance(0.4) {
+                image[cell] = '#';
+            }
+        }
+        // Monsters are placed one below the other, in rows three tall with
+        // a gap between, so they can't overlap. The top row and often the
+        // first monster in a row start at the edge, and the bottom row and
+        // often the last monster in a row end at it.
+        let mut rows: Vec<usize> = (0..=dim - monster_h).step_by(monster_h + 1).collect();
+        *rows.last_mut().unwrap() = dim - monster_h;
+        let mut planted = 0;
+        for &y in rows.iter() {
+            let mut xs = Vec::new();
+            let mut x = if rng.chance(0.5) {
+                0
+            } else {
+                rng.range(1, 20) as usize
+            };
+            while x + monster_w <= dim {
+                xs.push(x);
+                x += monster_w + 1 + rng.range(0, 10) as usize;
+            }
+            if let Some(last) = xs.last_mut() {
+                if rng.chance(0.5) {
+                    *last = dim - monster_w;
+                }
+            }
+            for &x in xs.iter() {
+                if rng.chance(0.3) {
+                    for &(dx, dy) in offsets.iter() {
+                        image[(x + dx, y + dy)] = '#';
+                    }
+                    planted += 1;
+                }
+            }
+        }
+        // Noise can make monsters of its own, rarely, and then the count
+        // would be off.
+        if planted > 0 && count_monsters(&image) == planted {
+            let rough = image.iter().filter(|&&c| c == '#').count() - planted * offsets.len();
+            return (image, rough);
+        }
+    }
+}
+
+/// Tiles for a `side` by `side` image, with `side` from `size` tiles but
+/// at least 3 so that sea monsters fit. The tiles' borders are the lines of a
+/// lattice, each picked so that it's unlike every other line even when
+/// reversed, which makes the assembly unique. Each tile is turned and
+/// flipped at random before it's written out.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let side = (1..).find(|n| n * n >= size).unwrap().clamp(3, 12);
+    let (image, rough) = generate_image(rng, side);
+
+    // Lattice points, then the horizontal and vertical lines between them,
+    // each including the points at its ends.
+    let points: Vec<Vec<char>> = (0..=side)
+        .map(|_| rng.string(".#", side + 1).chars().collect())
+        .collect();
+    let mut used = HashSet::new();
+    let mut line = |rng: &mut Rng, from: char, to: char| -> Vec<char> {
+        loop {
+            let line: String = format!("{}{}{}", from, rng.string(".#", TILE_WH - 2), to);
+            let reversed: String = line.chars().rev().collect();
+            if line != reversed && !used.contains(&line) && !used.contains(&reversed) {
+                used.insert(line.clone());
+                return line.chars().collect();
+            }
+        }
+    };
+    let mut across = Vec::new();
+    let mut down = Vec::new();
+    for row in points.iter() {
+        for ends in row.windows(2) {
+            across.push(line(rng, ends[0], ends[1]));
+        }
+    }
+    for x in 0..=side {
+        for rows in points.windows(2) {
+            down.push(line(rng, rows[0][x], rows[1][x]));
+        }
+    }
+
+    let mut ids: Vec<usize> = (1000..10000).collect();
+    rng.shuffle(&mut ids);
+    let mut tiles = Vec::new();
+    let inner = TILE_WH - 2;
+    for ty in 0..side {
+        for tx in 0..side {
+            let mut tile = Grid::filled(TILE_WH, TILE_WH, '.');
+            for i in 0..TILE_WH {
+                tile[(i, 0)] = across[ty * side + tx][i];
+                tile[(i, TILE_WH - 1)] = across[(ty + 1) * side + tx][i];
+                tile[(0, i)] = down[tx * side + ty][i];
+                tile[(TILE_WH - 1, i)] = down[(tx + 1) * side + ty][i];
+            }
+            for y in 0..inner {
+                for x in 0..inner {
+                    tile[(x + 1, y + 1)] = image[(tx * inner + x, ty * inner + y)];
+                }
+            }
+            for _ in 0..rng.index(4) {
+                tile = tile.rotate_cw();
+            }
+            if rng.chance(0.5) {
+                tile = tile.flip_lr();
+            }
+            tiles.push(format!("Tile {}:\n{}", ids[ty * side + tx], tile));
+        }
+    }
+    let corners: usize = [0, side - 1, side * (side - 1), side * side - 1]
+        .iter()
+        .map(|&i| ids[i])
+        .product();
+    rng.shuffle(&mut tiles);
+    Generated::new(tiles.join("\n")).part1(corners).part2(rough)
+}
+
+static EXAMPLE: &str = r#"Tile 2311:
+..##.#..#.
+##..#.....
+#...##..#.
+####.#...#
+##.##.###.
+##...#.###
+.#.#.#..##
+..#....#..
+###...#.#.
+..###..###
+
+Tile 1951:
+#.##...##.
+#.####...#
+.....#..##
+#...######
+.##.#....#
+.###.#####
+###.##.##.
+.###....#.
+..#.#..#.#
+#...##.#..
+
+Tile 1171:
+####...##.
+#..##.#..#
+##.#..#.#.
+.###.####.
+..###.####
+.##....##.
+.#...####.
+#.##.####.
+####..#...
+.....##...
+
+Tile 1427:
+###.##.#..
+.#..#.##..
+.#.##.#..#
+#.#.#.##.#
+....#...##
+...##..##.
+...#.#####
+.#.####.#.
+..#..###.#
+..##.#..#.
+
+Tile 1489:
+##.#.#....
+..##...#..
+.##..##...
+..#...#...
+#####...#.
+#..#.#.#.#
+...#.#.#..
+##.#...##.
+..##.##.##
+###.##.#..
+
+Tile 2473:
+#....####.
+#..#.##...
+#.##..#...
+######.#.#
+.#...#.#.#
+.#########
+.###.#..#.
+########.#
+##...##.#.
+..###.#.#.
+
+Tile 2971:
+..#.#....#
+#...###...
+#.#.###...
+##.##..#..
+.#####..##
+.#..####.#
+#..#.#..#.
+..####.###
+..#.#.###.
+...#.#.#.#
+
+Tile 2729:
+...#.#.#.#
+####.#....
+..#.#.....
+....#..#.#
+.##..##.#.
+.#.####...
+####.#.#..
+##.####...
+##..#.##..
+#.##...##.
+
+Tile 3079:
+#.#.#####.
+.#..######
+..#.......
+######....
+####.#..#.
+.#...#.##.
+#.#####.##
+..#.###...
+..#.......
+..#.###..."#;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed = Vec<Tile>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, tiles: &Self::Parsed) -> Answer {
+        classify_tiles(tiles)
+            .map(|(corner_ids, _, _)| corner_ids.iter().product::<usize>())
+            .into()
+    }
+
+    fn part2(&self, tiles: &Self::Parsed) -> Answer {
+        align_grid(tiles).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(20899048083289i64).part2(273)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let tile = "Tile 1:\n".to_string() + &"..........\n".repeat(9);
+        let err = parse(&tile).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 11, column 1: expected 10 rows, got 9"
+        );
+
+        let tiles = tile.clone() + "..........\n\n" + &tile.replace("Tile 1", "Tile x");
+        let err = parse(&tiles).unwrap_err();
+        assert_eq!(err.to_string(), "line 13, column 6: invalid number `x`");
+
+        let tiles = tile.clone() + "..........\n\n" + &tile + "..........\n";
+        let err = parse(&tiles).unwrap_err();
+        assert_eq!(err.to_string(), "line 13, column 1: tile 1 is given twice");
+    }
+
+    #[test]
+    fn test_monsters_at_the_edge() {
+        // A monster filling the whole image, so it touches every edge, plus
+        // one rough cell, turned so it's found sideways.
+        let image = MONSTER.replace(' ', ".") + "\n#...................";
+        let image = Grid::parse(&image, Some).unwrap().rotate_cw();
+        assert_eq!(count_monsters(&image), 1);
+        assert_eq!(rough_water(&image), 1);
+    }
+
+    #[test]
+    fn test_unassembled() {
+        // Random borders practically never line up.
+        let mut rng = Rng::new(20);
+        let tiles: Vec<String> = (1..=9)
+            .map(|id| {
+                let rows: Vec<String> = (0..TILE_WH).map(|_| rng.string(".#", TILE_WH)).collect();
+                format!("Tile {}:\n{}\n", id, rows.join("\n"))
+            })
+            .collect();
+        let tiles = parse(&tiles.join("\n")).unwrap();
+        assert_eq!(Day20.part1(&tiles), Answer::Unsolved);
+        assert_eq!(Day20.part2(&tiles), Answer::Unsolved);
+
+        // Blank tiles all match each other, so there are no corners.
+        let blank = "Tile 1:\n".to_string() + &"..........\n".repeat(TILE_WH);
+        let tiles: Vec<String> = (1..=4)
+            .map(|id| blank.replace("Tile 1", &format!("Tile {}", id)))
+            .collect();
+        let tiles = parse(&tiles.join("\n")).unwrap();
+        assert_eq!(Day20.part1(&tiles), Answer::Unsolved);
+        assert_eq!(Day20.part2(&tiles), Answer::Unsolved);
+    }
+}
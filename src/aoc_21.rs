@@ -0,0 +1,277 @@
+use crate::error::{parse_lines, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use std::collections::{HashMap, HashSet};
+
+/// A food's ingredients and the allergens it lists.
+type Food = (Vec<String>, Vec<String>);
+
+fn parse(input: &str) -> Result<Vec<Food>> {
+    let foods = parse_lines(input, |l| {
+        let delimiter = l
+            .find(" (contains ")
+            .ok_or_else(|| AocError::at_end(l, ErrorKind::Missing("`(contains ...)`")))?;
+        let allergens = l[delimiter + " (contains ".len()..]
+            .strip_suffix(')')
+            .ok_or_else(|| AocError::at_end(l, ErrorKind::Missing("`)`")))?;
+        Ok((
+            l[..delimiter].split(' ').map(str::to_string).collect(),
+            allergens.split(", ").map(str::to_string).collect(),
+        ))
+    })?;
+    if foods.is_empty() {
+        return Err(AocError::at_end(input, ErrorKind::Missing("foods")));
+    }
+    Ok(foods)
+}
+
+/// The number of appearances of ingredients that can't hold an allergen, and
+/// the dangerous ingredients sorted by allergen, or `None` if no assignment
+/// of allergens to ingredients fits every food.
+fn solve(input: &[Food]) -> Option<(usize, String)> {
+    let mut all_allergens: Vec<&str> = Vec::new();
+    let mut ingredient_map: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut allergen_map: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut foods: Vec<(Vec<&str>, Vec<&str>)> = Vec::new();
+    for (ingredients, allergens) in input {
+        let ingredients = ingredients.iter().map(String::as_str).collect::<Vec<_>>();
+        let allergens = allergens.iter().map(String::as_str).collect::<Vec<_>>();
+
+        for i in ingredients.iter() {
+            (*ingredient_map.entry(i).or_default()).append(&mut allergens.clone());
+        }
+        for a in allergens.iter() {
+            (*allergen_map.entry(a).or_default()).append(&mut ingredients.clone());
+        }
+
+        all_allergens.append(&mut allergens.clone());
+        foods.push((ingredients, allergens));
+    }
+    all_allergens.sort();
+    all_allergens.dedup();
+
+    for (_, v) in ingredient_map.iter_mut() {
+        v.sort();
+        v.dedup();
+    }
+    for (_, v) in allergen_map.iter_mut() {
+        v.sort();
+        v.dedup();
+    }
+
+    let depth = all_allergens.len();
+
+    let mut feasible_assignements: Vec<Vec<(&str, &str)>> = Vec::new();
+
+    let mut assignments_in_prog: Vec<(&str, &str)> = Vec::new();
+
+    let mut stack: Vec<(&str, &str, usize)> = Vec::new();
+    {
+        let allergen_to_assign = all_allergens[0];
+        debug!("first allergen: {}", allergen_to_assign);
+        debug!("allergen candidates: {:?}", allergen_map);
+        let options = &allergen_map[allergen_to_assign];
+        for o in options.iter() {
+            stack.push((allergen_to_assign, o, 0));
+        }
+    }
+
+    while let Some((all, ing, d)) = stack.pop() {
+        assignments_in_prog.resize(d, ("", ""));
+        assignments_in_prog.push((all, ing));
+
+        // evaluate rules
+        let mut violation: bool = false;
+        for (mut i, mut a) in foods.iter().cloned() {
+            for (ass_all, ass_ing) in assignments_in_prog.iter() {
+                if a.iter().find(|x| x == &ass_all).is_some() {
+                    if i.iter().find(|i| i == &ass_ing).is_none() {
+                        violation = true;
+                        break;
+                    }
+                    i.retain(|ii| ii != ass_ing);
+                    a.retain(|aa| aa != ass_all);
+                }
+            }
+            if violation {
+                break;
+            }
+        }
+
+        if violation {
+            continue;
+        }
+
+        // if passed rules and depth indicates that we've finished assigning
+        // then we have a feasible soln use log it.
+        if d == depth - 1 {
+            debug!("feasible: {:?}", assignments_in_prog);
+            feasible_assignements.push(assignments_in_prog.clone());
+            continue;
+        }
+
+        // pick next value to assign and push all the candidates
+        let allergen_to_assign = all_allergens[d + 1];
+        let mut options = allergen_map[allergen_to_assign].clone();
+        options.retain(|x| assignments_in_prog.iter().find(|(_, i)| x == i).is_none());
+        for o in options.iter() {
+            stack.push((allergen_to_assign, o, d + 1));
+        }
+    }
+
+    let feasible = feasible_assignements.first()?;
+    let impossible_ingredients = ingredient_map
+        .keys()
+        .filter(|i| feasible.iter().find(|ii| *i == &ii.1).is_none())
+        .collect::<Vec<_>>();
+
+    let impossible_count = foods
+        .iter()
+        .map(|(is, _)| {
+            is.iter()
+                .filter(|ii| {
+                    impossible_ingredients
+                        .iter()
+                        .find(|iii| iii == &ii)
+                        .is_some()
+                })
+                .count()
+        })
+        .sum::<usize>();
+
+    let mut string = feasible.iter().map(|(_, is)| *is).collect::<Vec<&str>>();
+    string.sort_by_key(|k| feasible.iter().find(|(_, i)| k == i).unwrap().0);
+    Some((impossible_count, string.join(",")))
+}
+
+static ALLERGENS: [&str; 8] = [
+    "dairy", "eggs", "fish", "nuts", "peanuts", "sesame", "soy", "wheat",
+];
+
+/// At least `size` foods, with allergens hidden in made-up ingredients and
+/// each food listing only some of the allergens it has. Foods are added
+/// until narrowing down each allergen's ingredient leaves only one choice
+/// for all of them.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut ingredients = HashSet::new();
+    while ingredients.len() < 40 + size.min(160) {
+        let len = rng.range(3, 8) as usize;
+        ingredients.insert(rng.string("abcdefghijklmnopqrstuvwxyz", len));
+    }
+    let mut ingredients: Vec<String> = ingredients.into_iter().collect();
+    ingredients.sort();
+    rng.shuffle(&mut ingredients);
+    let allergens = &ALLERGENS[..rng.range(2, ALLERGENS.len() as i64) as usize];
+    // Allergen `i` is in ingredient `i`.
+    let mut candidates: Vec<Option<HashSet<usize>>> = vec![None; allergens.len()];
+    let mut input = String::new();
+    let mut safe = 0;
+    let mut foods = 0;
+    loop {
+        let mut food: Vec<usize> = (0..ingredients.len())
+            .filter(|&i| rng.chance(if i < allergens.len() { 0.5 } else { 0.3 }))
+            .collect();
+        let mut listed: Vec<usize> = food
+            .iter()
+            .copied()
+            .filter(|&i| i < allergens.len() && rng.chance(0.5))
+            .collect();
+        if listed.is_empty() {
+            let i = rng.index(allergens.len());
+            if !food.contains(&i) {
+                food.push(i);
+            }
+            listed.push(i);
+        }
+        for &a in listed.iter() {
+            let here: HashSet<usize> = food.iter().copied().collect();
+            candidates[a] = Some(match &candidates[a] {
+                Some(c) => c.intersection(&here).copied().collect(),
+                None => here,
+            });
+        }
+        safe += food.iter().filter(|&&i| i >= allergens.len()).count();
+        rng.shuffle(&mut food);
+        let names: Vec<&str> = food.iter().map(|&i| ingredients[i].as_str()).collect();
+        let listed: Vec<&str> = listed.iter().map(|&a| allergens[a]).collect();
+        input += &format!("{} (contains {})\n", names.join(" "), listed.join(", "));
+        foods += 1;
+
+        // Settle allergens with one candidate left until none change.
+        let mut settled = vec![false; allergens.len()];
+        let mut remaining = candidates.clone();
+        while let Some(a) = (0..allergens.len())
+            .find(|&a| !settled[a] && matches!(&remaining[a], Some(c) if c.len() == 1))
+        {
+            settled[a] = true;
+            let i = *remaining[a].as_ref().unwrap().iter().next().unwrap();
+            for (b, c) in remaining.iter_mut().enumerate() {
+                if let Some(c) = c {
+                    if b != a {
+                        c.remove(&i);
+                    }
+                }
+            }
+        }
+        if foods >= size && settled.iter().all(|&s| s) {
+            break;
+        }
+    }
+    let dangerous: Vec<&str> = ingredients[..allergens.len()]
+        .iter()
+        .map(String::as_str)
+        .collect();
+    Generated::new(input).part1(safe).part2(dangerous.join(","))
+}
+
+static EXAMPLE: &str = r#"mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)"#;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Parsed = Vec<Food>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Parsed) -> Answer {
+        solve(input).map(|(count, _)| count).into()
+    }
+
+    fn part2(&self, input: &Self::Parsed) -> Answer {
+        solve(input).map(|(_, list)| list).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(5).part2("mxmxvkd,sqjhc,fvjkl")]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let (impossible_count, canon_string) = solve(&parse(EXAMPLE).unwrap()).unwrap();
+        assert_eq!(impossible_count, 5);
+        assert_eq!(canon_string, "mxmxvkd,sqjhc,fvjkl");
+    }
+
+    #[test]
+    fn test_blank_lines_and_no_assignment() {
+        let input = format!("\n{}\n\n", EXAMPLE);
+        assert_eq!(solve(&parse(&input).unwrap()).unwrap().0, 5);
+
+        let foods = parse("a (contains dairy)\nb (contains dairy)\n").unwrap();
+        assert_eq!(solve(&foods), None);
+    }
+}
@@ -0,0 +1,283 @@
+use crate::error::{parse_num, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+fn simulate(mut p1: VecDeque<i64>, mut p2: VecDeque<i64>) -> (usize, VecDeque<i64>, i64) {
+    while !p1.is_empty() && !p2.is_empty() {
+        let card_p1 = p1.pop_front().unwrap();
+        let card_p2 = p2.pop_front().unwrap();
+
+        match card_p1.partial_cmp(&card_p2).unwrap() {
+            std::cmp::Ordering::Greater => {
+                p1.push_back(card_p1);
+                p1.push_back(card_p2);
+            }
+            std::cmp::Ordering::Less => {
+                p2.push_back(card_p2);
+                p2.push_back(card_p1);
+            }
+            // `parse` rejects decks that share a card.
+            std::cmp::Ordering::Equal => unreachable!(),
+        }
+    }
+
+    let (winner, winner_deck) = if p1.is_empty() { (2, p2) } else { (1, p1) };
+
+    let sum = winner_deck
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, c)| (i + 1) as i64 * c)
+        .sum::<i64>();
+    (winner, winner_deck, sum)
+}
+fn simulate_p2(mut p1: VecDeque<i64>, mut p2: VecDeque<i64>) -> (bool, VecDeque<i64>) {
+    let mut record: HashSet<(Vec<i64>, Vec<i64>)> = HashSet::new();
+
+    while !p1.is_empty() && !p2.is_empty() {
+        if !record.insert((p1.iter().copied().collect(), p2.iter().copied().collect())) {
+            return (true, p1);
+        }
+        let card_p1 = p1.pop_front().unwrap();
+        let card_p2 = p2.pop_front().unwrap();
+
+        let p1_enough = card_p1 as usize <= p1.len();
+        let p2_enough = card_p2 as usize <= p2.len();
+        let p1_wins = if p1_enough && p2_enough {
+            let sub_p1 = p1
+                .iter()
+                .cloned()
+                .take(card_p1 as usize)
+                .collect::<VecDeque<_>>();
+            let sub_p2 = p2
+                .iter()
+                .cloned()
+                .take(card_p2 as usize)
+                .collect::<VecDeque<_>>();
+
+            simulate_p2_impl(sub_p1, sub_p2)
+        } else {
+            card_p1 > card_p2
+        };
+
+        if p1_wins {
+            p1.push_back(card_p1);
+            p1.push_back(card_p2);
+        } else {
+            p2.push_back(card_p2);
+            p2.push_back(card_p1);
+        }
+    }
+
+    if p2.is_empty() {
+        (true, p1)
+    } else {
+        (false, p2)
+    }
+}
+
+fn simulate_p2_impl(mut p1: VecDeque<i64>, mut p2: VecDeque<i64>) -> bool {
+    let mut record: HashSet<(Vec<i64>, Vec<i64>)> = HashSet::new();
+
+    while !p1.is_empty() && !p2.is_empty() {
+        if !record.insert((p1.iter().copied().collect(), p2.iter().copied().collect())) {
+            return true;
+        }
+        let card_p1 = p1.pop_front().unwrap();
+        let card_p2 = p2.pop_front().unwrap();
+
+        let p1_enough = card_p1 as usize <= p1.len();
+        let p2_enough = card_p2 as usize <= p2.len();
+        let p1_wins = if p1_enough && p2_enough {
+            let sub_p1 = p1
+                .iter()
+                .cloned()
+                .take(card_p1 as usize)
+                .collect::<VecDeque<_>>();
+            let sub_p2 = p2
+                .iter()
+                .cloned()
+                .take(card_p2 as usize)
+                .collect::<VecDeque<_>>();
+            let (sub_winner, _) = simulate_p2(sub_p1, sub_p2);
+            sub_winner
+        } else {
+            card_p1 > card_p2
+        };
+
+        if p1_wins {
+            p1.push_back(card_p1);
+            p1.push_back(card_p2);
+        } else {
+            p2.push_back(card_p2);
+            p2.push_back(card_p1);
+        }
+    }
+
+    p2.is_empty()
+}
+
+fn parse(input: &str) -> Result<(VecDeque<i64>, VecDeque<i64>)> {
+    // Every card in the puzzle is different, which the games rely on.
+    let mut seen: HashSet<i64> = HashSet::new();
+    let mut map_iter = input.split("\n\n").map(|p| {
+        p.lines()
+            .skip(1)
+            .filter(|x| !x.is_empty())
+            .map(|x| {
+                let card = parse_num(input, x)?;
+                if !seen.insert(card) {
+                    return Err(AocError::at(
+                        input,
+                        x,
+                        ErrorKind::Invalid(format!("card {} is dealt twice", card)),
+                    ));
+                }
+                Ok(card)
+            })
+            .collect::<Result<VecDeque<_>>>()
+    });
+    let p1 = map_iter
+        .next()
+        .ok_or_else(|| AocError::at_end(input, ErrorKind::Missing("player 1's deck")))??;
+    let p2 = map_iter
+        .next()
+        .ok_or_else(|| AocError::at_end(input, ErrorKind::Missing("player 2's deck")))??;
+    Ok((p1, p2))
+}
+
+fn score_p2(p1: VecDeque<i64>, p2: VecDeque<i64>) -> i64 {
+    let (_winner, deck) = simulate_p2(p1, p2);
+    deck.iter()
+        .rev()
+        .enumerate()
+        .map(|(i, c)| (i + 1) as i64 * c)
+        .sum::<i64>()
+}
+
+/// Plays a game of Combat, or Recursive Combat if `recursive`, returning
+/// whether player 1 won and the winner's deck. Plain Combat can go on
+/// forever, so that gives up with `None` after `max_rounds`.
+fn play(
+    mut p1: VecDeque<i64>,
+    mut p2: VecDeque<i64>,
+    recursive: bool,
+    max_rounds: usize,
+) -> Option<(bool, VecDeque<i64>)> {
+    let mut seen = HashSet::new();
+    for _ in 0..max_rounds {
+        if p1.is_empty() || p2.is_empty() {
+            let p1_won = p2.is_empty();
+            return Some((p1_won, if p1_won { p1 } else { p2 }));
+        }
+        if recursive && !seen.insert((p1.clone(), p2.clone())) {
+            return Some((true, p1));
+        }
+        let (a, b) = (p1.pop_front().unwrap(), p2.pop_front().unwrap());
+        let p1_wins = if recursive && a as usize <= p1.len() && b as usize <= p2.len() {
+            let sub1 = p1.iter().copied().take(a as usize).collect();
+            let sub2 = p2.iter().copied().take(b as usize).collect();
+            play(sub1, sub2, true, max_rounds)?.0
+        } else {
+            a > b
+        };
+        if p1_wins {
+            p1.extend([a, b].iter());
+        } else {
+            p2.extend([b, a].iter());
+        }
+    }
+    None
+}
+
+/// A shuffled deck of `size` cards dealt between the two players, scored by
+/// playing both games. Recursive Combat slows down quickly with bigger
+/// decks, so there are at most 50 like the real inputs. Decks that would
+/// make plain Combat go on forever are reshuffled.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let count = (size.clamp(2, 50) / 2 * 2) as i64;
+    let mut cards: Vec<i64> = (1..=count).collect();
+    loop {
+        rng.shuffle(&mut cards);
+        let (p1, p2): (VecDeque<i64>, VecDeque<i64>) = (
+            cards[..cards.len() / 2].iter().copied().collect(),
+            cards[cards.len() / 2..].iter().copied().collect(),
+        );
+        let score = |deck: VecDeque<i64>| -> i64 {
+            deck.iter()
+                .rev()
+                .enumerate()
+                .map(|(i, c)| (i + 1) as i64 * c)
+                .sum()
+        };
+        let combat = match play(p1.clone(), p2.clone(), false, 100_000) {
+            Some((_, deck)) => score(deck),
+            None => continue,
+        };
+        let (_, deck) = play(p1.clone(), p2.clone(), true, usize::MAX).unwrap();
+        let format =
+            |deck: &VecDeque<i64>| -> String { deck.iter().map(|c| format!("{}\n", c)).collect() };
+        let input = format!("Player 1:\n{}\nPlayer 2:\n{}", format(&p1), format(&p2));
+        return Generated::new(input).part1(combat).part2(score(deck));
+    }
+}
+
+static EXAMPLE: &str = "Player 1:\n9\n2\n6\n3\n1\n\nPlayer 2:\n5\n8\n4\n7\n10\n";
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Parsed = (VecDeque<i64>, VecDeque<i64>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, (p1, p2): &Self::Parsed) -> Answer {
+        let (_winner, _deck, score) = simulate(p1.clone(), p2.clone());
+        score.into()
+    }
+
+    fn part2(&self, (p1, p2): &Self::Parsed) -> Answer {
+        score_p2(p1.clone(), p2.clone()).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(306).part2(291)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let p1: VecDeque<i64> = VecDeque::from(vec![9, 2, 6, 3, 1]);
+        let p2: VecDeque<i64> = VecDeque::from(vec![5, 8, 4, 7, 10]);
+
+        let (winner, _deck, score) = simulate(p1.clone(), p2.clone());
+        assert_eq!(winner, 2);
+        assert_eq!(score, 306);
+
+        assert_eq!(score_p2(p1, p2), 291);
+    }
+
+    #[test]
+    fn test_repeated_card() {
+        assert_eq!(
+            parse("Player 1:\n3\n1\n\nPlayer 2:\n2\n3\n"),
+            Err(AocError::new(
+                7,
+                1,
+                ErrorKind::Invalid("card 3 is dealt twice".to_string())
+            ))
+        );
+    }
+}
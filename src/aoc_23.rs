@@ -0,0 +1,238 @@
+use crate::error::{AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use std::convert::TryInto;
+
+struct Cups1 {
+    cups: [usize; 9],
+    current_cup_idx: usize,
+}
+
+fn wrap(mut x: i64) -> i64 {
+    x = (x - 1) % 9;
+    if x < 0 {
+        10 + x
+    } else {
+        1 + x
+    }
+}
+
+impl Cups1 {
+    fn round(&mut self) {
+        let pulled_cup_indices: [usize; 3] = [
+            (self.current_cup_idx + 1) % 9,
+            (self.current_cup_idx + 2) % 9,
+            (self.current_cup_idx + 3) % 9,
+        ];
+        let cups_pulled: [usize; 3] = [
+            self.cups[pulled_cup_indices[0]],
+            self.cups[pulled_cup_indices[1]],
+            self.cups[pulled_cup_indices[2]],
+        ];
+
+        let destination_idx = {
+            let mut current_label = self.cups[self.current_cup_idx] - 1;
+            if current_label == 0 {
+                current_label = 9;
+            }
+            let mut maybe_dest_idx = self.cups.iter().position(|&c| c == current_label).unwrap();
+            loop {
+                if pulled_cup_indices.contains(&maybe_dest_idx) {
+                    current_label -= 1;
+                    if current_label == 0 {
+                        current_label = 9;
+                    }
+                } else {
+                    break;
+                }
+                maybe_dest_idx = self.cups.iter().position(|&c| c == current_label).unwrap();
+            }
+            maybe_dest_idx + 1
+        };
+        trace!("destination index {}", destination_idx);
+
+        // slide down
+        let mut idx = pulled_cup_indices[0];
+        while idx != destination_idx % 9 {
+            self.cups[idx] = self.cups[(idx + 3) % 9];
+            idx = (idx + 1) % 9
+        }
+        trace!("after sliding: {:?}", self.cups);
+
+        // write destination
+        for (i, &cup) in cups_pulled.iter().enumerate() {
+            let dest = (wrap(destination_idx as i64 - 3 + i as i64) % 9) as usize;
+            self.cups[dest] = cup;
+        }
+        trace!("after writing: {:?}", self.cups);
+
+        // pick current cup
+        self.current_cup_idx = (self.current_cup_idx + 1) % 9;
+    }
+
+    fn labels_after_1(&self) -> String {
+        let idx_1 = self.cups.iter().position(|&c| c == 1).unwrap();
+        (1..9)
+            .map(|i| self.cups[(idx_1 + i) % 9].to_string())
+            .collect()
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<usize>> {
+    let trimmed = input.trim();
+    let mut labels: Vec<usize> = Vec::new();
+    for (i, c) in trimmed.char_indices() {
+        match c.to_digit(10) {
+            Some(d) if d != 0 && !labels.contains(&(d as usize)) => labels.push(d as usize),
+            _ => {
+                return Err(AocError::at(
+                    input,
+                    &trimmed[i..],
+                    ErrorKind::UnexpectedChar(c),
+                ))
+            }
+        }
+    }
+    if labels.len() != 9 {
+        return Err(AocError::at(
+            input,
+            trimmed,
+            ErrorKind::Invalid("expected each of the cups 1 to 9 once".to_string()),
+        ));
+    }
+    Ok(labels)
+}
+
+fn play_p1(labels: &[usize], rounds: usize) -> String {
+    let mut cups = Cups1 {
+        cups: labels.try_into().unwrap(),
+        current_cup_idx: 0,
+    };
+
+    for _ in 0..rounds {
+        trace!("cups: {:?}", cups.cups);
+        cups.round();
+    }
+    debug!("final cups: {:?}", cups.cups);
+    cups.labels_after_1()
+}
+
+fn play_p2(labels: &[usize]) -> usize {
+    let order: Vec<usize> = labels.iter().copied().chain(10..1000001).collect();
+    let mut v: Vec<usize> = vec![usize::MAX; order.len() + 1];
+    for w in order.windows(2) {
+        v[w[0]] = w[1];
+    }
+    v[*order.last().unwrap()] = order[0];
+
+    let mut current_cup_label = order[0];
+
+    for i in 0..10000001 {
+        trace!("move {}", i);
+        let cups_pulled: [usize; 3] = [
+            v[current_cup_label], // random access
+            v[v[current_cup_label]],
+            v[v[v[current_cup_label]]],
+        ];
+        let dest_label = {
+            let mut dest_label = current_cup_label - 1;
+            if dest_label == 0 {
+                dest_label = 1000000;
+            }
+
+            while cups_pulled.contains(&dest_label) {
+                dest_label -= 1;
+                if dest_label == 0 {
+                    dest_label = 1000000;
+                }
+            }
+            dest_label
+        };
+
+        // make the current cup point to the cup just
+        // past the ones we pulled
+        v[current_cup_label] = v[cups_pulled[2]];
+
+        // left of destination points to first pulled cup
+        let next_after_dest = v[dest_label];
+        v[dest_label] = cups_pulled[0];
+
+        // last pulled cup points to next after destination
+        v[cups_pulled[2]] = next_after_dest;
+
+        current_cup_label = v[current_cup_label];
+    }
+
+    let cup_after_1 = v[1];
+    let cup_after_after_1 = v[v[1]];
+    cup_after_1 * cup_after_after_1
+}
+
+/// The nine cups in a random order; `size` doesn't matter here. Part 1
+/// comes from playing the moves on a plain list, with the current cup
+/// always kept at the front.
+fn generate(rng: &mut Rng, _size: usize) -> Generated {
+    let mut cups: Vec<usize> = (1..=9).collect();
+    rng.shuffle(&mut cups);
+    let input: String = cups.iter().map(|c| c.to_string()).collect::<String>() + "\n";
+    for _ in 0..100 {
+        let current = cups[0];
+        let picked: Vec<usize> = cups.drain(1..4).collect();
+        let mut destination = current;
+        loop {
+            destination = if destination == 1 { 9 } else { destination - 1 };
+            if !picked.contains(&destination) {
+                break;
+            }
+        }
+        let at = cups.iter().position(|&c| c == destination).unwrap() + 1;
+        cups.splice(at..at, picked);
+        cups.rotate_left(1);
+    }
+    let one = cups.iter().position(|&c| c == 1).unwrap();
+    cups.rotate_left(one);
+    let labels: String = cups[1..].iter().map(|c| c.to_string()).collect();
+    Generated::new(input).part1(labels)
+}
+
+static EXAMPLE: &str = "389125467\n";
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, labels: &Self::Parsed) -> Answer {
+        play_p1(labels, 100).into()
+    }
+
+    fn part2(&self, labels: &Self::Parsed) -> Answer {
+        play_p2(labels).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE)
+            .part1("67384529")
+            .part2(149245887792i64)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let labels = parse(EXAMPLE).unwrap();
+        assert_eq!(play_p1(&labels, 10), "92658374");
+        assert_eq!(play_p1(&labels, 100), "67384529");
+    }
+}
@@ -0,0 +1,180 @@
+use crate::automaton::{Automaton, Rule};
+use crate::error::{parse_lines, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub enum Step {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl FromStr for Step {
+    type Err = AocError;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "e" => Ok(Step::East),
+            "se" => Ok(Step::SouthEast),
+            "sw" => Ok(Step::SouthWest),
+            "w" => Ok(Step::West),
+            "ne" => Ok(Step::NorthEast),
+            "nw" => Ok(Step::NorthWest),
+            _ => Err(AocError::at(
+                s,
+                s,
+                ErrorKind::Unknown {
+                    what: "direction",
+                    token: s.to_string(),
+                },
+            )),
+        }
+    }
+}
+
+fn steps_to_coor(steps: &[Step]) -> (i64, i64) {
+    steps.iter().fold((0, 0), |acc, s| {
+        let s_coor = match s {
+            Step::East => (2, 0),
+            Step::SouthEast => (1, -2),
+            Step::SouthWest => (-1, -2),
+            Step::West => (-2, 0),
+            Step::NorthWest => (-1, 2),
+            Step::NorthEast => (1, 2),
+        };
+        (acc.0 + s_coor.0, acc.1 + s_coor.1)
+    })
+}
+
+fn parse_line(l: &str) -> Result<Vec<Step>> {
+    let mut steps = Vec::new();
+    let mut i = 0;
+    while i < l.len() {
+        let len = if l[i..].starts_with('n') || l[i..].starts_with('s') {
+            2
+        } else {
+            1
+        };
+        let step_slice = l.get(i..i + len).unwrap_or(&l[i..]);
+        steps.push(Step::from_str(step_slice).map_err(|e| e.within(l, step_slice))?);
+        i += len;
+    }
+    Ok(steps)
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<Step>>> {
+    parse_lines(input, parse_line)
+}
+
+/// Offsets to the six neighbors of a hex tile, in the doubled coordinates
+/// used by `steps_to_coor`.
+static HEX_NEIGHBORS: [(i64, i64); 6] = [(2, 0), (1, -2), (-1, -2), (-2, 0), (-1, 2), (1, 2)];
+
+fn flip_initial(steps: &[Vec<Step>]) -> Automaton<(i64, i64)> {
+    let mut floor = Automaton::new(HEX_NEIGHBORS.to_vec(), Rule::new(&[2], &[1, 2]));
+    for s in steps.iter() {
+        let coor = steps_to_coor(s);
+        floor.set(coor, !floor.is_alive(coor));
+    }
+    floor
+}
+
+fn black_after_days(steps: &[Vec<Step>], days: usize) -> usize {
+    let mut floor = flip_initial(steps);
+    floor.run(days);
+    floor.live_count()
+}
+
+/// `size` random paths. Some are earlier paths with their steps shuffled,
+/// which end on the same tile and flip it back. Part 1 counts the tiles
+/// flipped an odd number of times, tracked in axial coordinates; part 2
+/// has no shortcut.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let directions = ["e", "se", "sw", "w", "nw", "ne"];
+    let axial = [(1, 0), (0, 1), (-1, 1), (-1, 0), (0, -1), (1, -1)];
+    let mut paths: Vec<Vec<usize>> = Vec::new();
+    let mut black = HashSet::new();
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut path = if !paths.is_empty() && rng.chance(0.2) {
+            rng.choose(&paths).clone()
+        } else {
+            (0..rng.range(1, 20)).map(|_| rng.index(6)).collect()
+        };
+        rng.shuffle(&mut path);
+        let tile = path
+            .iter()
+            .fold((0, 0), |(q, r), &d| (q + axial[d].0, r + axial[d].1));
+        if !black.remove(&tile) {
+            black.insert(tile);
+        }
+        input.extend(path.iter().map(|&d| directions[d]));
+        input.push('\n');
+        paths.push(path);
+    }
+    Generated::new(input).part1(black.len())
+}
+
+static EXAMPLE: &str = r#"sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew"#;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed = Vec<Vec<Step>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, steps: &Self::Parsed) -> Answer {
+        black_after_days(steps, 0).into()
+    }
+
+    fn part2(&self, steps: &Self::Parsed) -> Answer {
+        black_after_days(steps, 100).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(10).part2(2208)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let steps = parse(EXAMPLE).unwrap();
+        assert_eq!(black_after_days(&steps, 0), 10);
+        assert_eq!(black_after_days(&steps, 100), 2208);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::error::{parse_num, required, AocError, ErrorKind, Result};
+use crate::rng::Rng;
+use crate::solution::{Answer, Example, Generated, Solution};
+
+const MODULUS: usize = 20201227;
+
+/// Parses a public key, which has to be a power of 7 modulo `MODULUS`.
+fn parse_key(input: &str, token: &str) -> Result<usize> {
+    match parse_num(input, token)? {
+        key if (1..MODULUS).contains(&key) => Ok(key),
+        _ => Err(AocError::at(
+            input,
+            token,
+            ErrorKind::Invalid(format!("public key must be between 1 and {}", MODULUS - 1)),
+        )),
+    }
+}
+
+fn parse(input: &str) -> Result<(usize, usize)> {
+    let mut keys = input.lines();
+    let card_pub = parse_key(input, required(input, keys.next(), "card public key")?)?;
+    let door_pub = parse_key(input, required(input, keys.next(), "door public key")?)?;
+    Ok((card_pub, door_pub))
+}
+
+/// The key both sides arrive at, or `None` if no loop size gives the card's
+/// public key.
+fn encryption_key(card_pub: usize, door_pub: usize) -> Option<usize> {
+    let sub: usize = 7;
+    let mut val: usize = 1;
+    // Powers of 7 repeat after at most `MODULUS - 1` steps.
+    let loop_count = (1..MODULUS).find(|_| {
+        val = (sub * val) % MODULUS;
+        val == card_pub
+    })?;
+    debug!("card loop size: {}", loop_count);
+
+    let sub: usize = door_pub;
+    let mut val: usize = 1;
+    for _ in 0..loop_count {
+        // loop size for A
+        let new = (sub * val) % MODULUS;
+        val = new;
+    }
+    Some(val)
+}
+
+fn mod_pow(mut base: u64, mut exp: u64) -> u64 {
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % MODULUS as u64;
+        }
+        base = base * base % MODULUS as u64;
+        exp >>= 1;
+    }
+    result
+}
+
+/// Public keys from random loop sizes of up to `size` * 10000, which is
+/// how long the search for the card's loop size takes. The key both sides
+/// arrive at is 7 to the power of both loop sizes.
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let max = (size.max(1) as u64 * 10_000).min(MODULUS as u64 - 2);
+    let card_loop = rng.below(max) + 1;
+    let door_loop = rng.below(max) + 1;
+    let input = format!("{}\n{}\n", mod_pow(7, card_loop), mod_pow(7, door_loop));
+    Generated::new(input).part1(mod_pow(mod_pow(7, card_loop), door_loop) as usize)
+}
+
+static EXAMPLE: &str = "5764801\n17807724\n";
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed = (usize, usize);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, (card_pub, door_pub): &Self::Parsed) -> Answer {
+        encryption_key(*card_pub, *door_pub).into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(EXAMPLE).part1(14897079)]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(encryption_key(5764801, 17807724), Some(14897079));
+    }
+
+    #[test]
+    fn test_key_range() {
+        assert_eq!(
+            parse("0\n17807724\n"),
+            Err(AocError::new(
+                1,
+                1,
+                ErrorKind::Invalid("public key must be between 1 and 20201226".to_string())
+            ))
+        );
+        assert_eq!(parse("5764801\n20201227\n").unwrap_err().line, 2);
+        // 1 is 7 to the power of `MODULUS - 1`, the last loop size tried.
+        assert_eq!(encryption_key(1, 17807724), Some(1));
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// A point on an unbounded integer lattice.
+pub trait Coord: Copy + Eq + Hash {
+    const DIMS: usize;
+
+    /// Builds a point from exactly `DIMS` components.
+    fn from_components(components: &[i64]) -> Self;
+
+    fn offset(self, by: Self) -> Self;
+}
+
+macro_rules! impl_coord {
+    ($dims:expr, $($i:tt),+) => {
+        impl Coord for ($(impl_coord!(@i64 $i),)+) {
+            const DIMS: usize = $dims;
+
+            fn from_components(components: &[i64]) -> Self {
+                ($(components[$i],)+)
+            }
+
+            fn offset(self, by: Self) -> Self {
+                ($(self.$i + by.$i,)+)
+            }
+        }
+    };
+    (@i64 $i:tt) => { i64 };
+}
+
+impl_coord!(2, 0, 1);
+impl_coord!(3, 0, 1, 2);
+impl_coord!(4, 0, 1, 2, 3);
+
+/// Offsets to every point within one step in each dimension, e.g. the 8
+/// surrounding squares in 2D or the 80 surrounding hypercubes in 4D.
+pub fn moore_neighborhood<C: Coord>() -> Vec<C> {
+    let mut offsets = Vec::new();
+    let mut components = vec![-1; C::DIMS];
+    loop {
+        if components.iter().any(|&c| c != 0) {
+            offsets.push(C::from_components(&components));
+        }
+        // Count through every combination of -1, 0 and 1 like an odometer.
+        match components.iter().position(|&c| c < 1) {
+            Some(i) => {
+                components[i] += 1;
+                for c in components[..i].iter_mut() {
+                    *c = -1;
+                }
+            }
+            None => return offsets,
+        }
+    }
+}
+
+/// Which live neighbor counts bring a dead cell to life and which keep a live
+/// cell alive, e.g. B3/S23 for Conway's Life.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    birth: Vec<usize>,
+    survival: Vec<usize>,
+}
+
+impl Rule {
+    pub fn new(birth: &[usize], survival: &[usize]) -> Rule {
+        Rule {
+            birth: birth.to_vec(),
+            survival: survival.to_vec(),
+        }
+    }
+
+    pub fn next(&self, alive: bool, live_neighbors: usize) -> bool {
+        if alive {
+            self.survival.contains(&live_neighbors)
+        } else {
+            self.birth.contains(&live_neighbors)
+        }
+    }
+}
+
+/// A cellular automaton on an unbounded lattice that only stores its live
+/// cells, so it can grow in any direction.
+#[derive(Debug, Clone)]
+pub struct Automaton<C: Coord> {
+    live: HashSet<C>,
+    stencil: Vec<C>,
+    rule: Rule,
+}
+
+impl<C: Coord> Automaton<C> {
+    /// An automaton with no live cells whose neighbors are the offsets in
+    /// `stencil`.
+    pub fn new(stencil: Vec<C>, rule: Rule) -> Automaton<C> {
+        Automaton {
+            live: HashSet::new(),
+            stencil,
+            rule,
+        }
+    }
+
+    pub fn set(&mut self, cell: C, alive: bool) {
+        if alive {
+            self.live.insert(cell);
+        } else {
+            self.live.remove(&cell);
+        }
+    }
+
+    pub fn is_alive(&self, cell: C) -> bool {
+        self.live.contains(&cell)
+    }
+
+    pub fn live_cells(&self) -> impl Iterator<Item = &C> {
+        self.live.iter()
+    }
+
+    pub fn live_count(&self) -> usize {
+        self.live.len()
+    }
+
+    /// Advances one generation. Only live cells and their neighbors can
+    /// change, so those are the only ones visited.
+    pub fn step(&mut self) {
+        let mut counts: HashMap<C, usize> = HashMap::new();
+        for &cell in self.live.iter() {
+            for &offset in self.stencil.iter() {
+                *counts.entry(cell.offset(offset)).or_insert(0) += 1;
+            }
+        }
+        let rule = &self.rule;
+        let live = &self.live;
+        let survivors = live
+            .iter()
+            .filter(|cell| rule.next(true, counts.get(cell).copied().unwrap_or(0)))
+            .copied();
+        let births = counts
+            .iter()
+            .filter(|&(cell, &n)| !live.contains(cell) && rule.next(false, n))
+            .map(|(&cell, _)| cell);
+        self.live = survivors.chain(births).collect();
+    }
+
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighborhood() {
+        assert_eq!(moore_neighborhood::<(i64, i64)>().len(), 8);
+        assert_eq!(moore_neighborhood::<(i64, i64, i64)>().len(), 26);
+        assert_eq!(moore_neighborhood::<(i64, i64, i64, i64)>().len(), 80);
+        assert!(!moore_neighborhood::<(i64, i64)>().contains(&(0, 0)));
+    }
+
+    #[test]
+    fn test_blinker() {
+        let mut life = Automaton::new(moore_neighborhood(), Rule::new(&[3], &[2, 3]));
+        for x in -1..=1 {
+            life.set((x, 0), true);
+        }
+        life.step();
+        let mut cells: Vec<(i64, i64)> = life.live_cells().copied().collect();
+        cells.sort();
+        assert_eq!(cells, vec![(0, -1), (0, 0), (0, 1)]);
+        life.run(3);
+        assert_eq!(life.live_count(), 3);
+        assert!(life.is_alive((1, 0)));
+    }
+}
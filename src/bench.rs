@@ -0,0 +1,193 @@
+use crate::error::{parse_lines, parse_num, required, AocError, ErrorKind, Result};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+pub static STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Summary of repeated timings of one stage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Stats {
+        samples.sort();
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Timings for one day, in the order of `STAGES`.
+#[derive(Debug, Clone)]
+pub struct DayTimings {
+    pub day: usize,
+    pub stages: [Stats; 3],
+}
+
+/// Saved timings keyed by day and stage name.
+pub type Results = BTreeMap<(usize, String), Stats>;
+
+impl DayTimings {
+    pub fn record(&self, results: &mut Results) {
+        for (stage, stats) in STAGES.iter().zip(self.stages.iter()) {
+            results.insert((self.day, stage.to_string()), *stats);
+        }
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let x = black_box(f());
+    (x, start.elapsed())
+}
+
+/// Parses and solves `input` `runs` times, timing each stage separately.
+pub fn bench_day(day: usize, input: &str, runs: usize) -> Result<DayTimings> {
+    let solution = crate::solution(day).expect("no solution for day");
+    let mut samples = [Vec::new(), Vec::new(), Vec::new()];
+    for _ in 0..runs.max(1) {
+        let (parsed, t) = timed(|| solution.parse_dyn(input));
+        let parsed = parsed?;
+        samples[0].push(t);
+        samples[1].push(timed(|| solution.part1_dyn(parsed.as_ref())).1);
+        samples[2].push(timed(|| solution.part2_dyn(parsed.as_ref())).1);
+    }
+    let [mut parse, mut part1, mut part2] = samples;
+    Ok(DayTimings {
+        day,
+        stages: [
+            Stats::from_samples(&mut parse),
+            Stats::from_samples(&mut part1),
+            Stats::from_samples(&mut part2),
+        ],
+    })
+}
+
+/// Formats a duration with a unit that keeps 3-4 significant digits.
+pub fn format_duration(d: Duration) -> String {
+    let ns = d.as_nanos() as f64;
+    if ns < 1e3 {
+        format!("{}ns", ns)
+    } else if ns < 1e6 {
+        format!("{:.1}µs", ns / 1e3)
+    } else if ns < 1e9 {
+        format!("{:.1}ms", ns / 1e6)
+    } else {
+        format!("{:.2}s", ns / 1e9)
+    }
+}
+
+/// Serialises timings as tab-separated `day stage min median max` rows, in
+/// nanoseconds.
+pub fn to_text(results: &Results) -> String {
+    let mut text = String::from("# day\tstage\tmin_ns\tmedian_ns\tmax_ns\n");
+    for ((day, stage), s) in results.iter() {
+        writeln!(
+            text,
+            "{}\t{}\t{}\t{}\t{}",
+            day,
+            stage,
+            s.min.as_nanos(),
+            s.median.as_nanos(),
+            s.max.as_nanos()
+        )
+        .unwrap();
+    }
+    text
+}
+
+/// Reads timings written by `to_text`, keyed by day and stage.
+pub fn from_text(text: &str) -> Result<Results> {
+    let rows = parse_lines(text, |line| {
+        if line.starts_with('#') {
+            return Ok(None);
+        }
+        let mut fields = line.split('\t');
+        let mut field = |what| required(line, fields.next(), what);
+        let day = parse_num(line, field("day")?)?;
+        let stage = field("stage")?;
+        if !STAGES.contains(&stage) {
+            return Err(AocError::at(
+                line,
+                stage,
+                ErrorKind::Unknown {
+                    what: "stage",
+                    token: stage.to_string(),
+                },
+            ));
+        }
+        let mut nanos =
+            |what| -> Result<Duration> { Ok(Duration::from_nanos(parse_num(line, field(what)?)?)) };
+        let stats = Stats {
+            min: nanos("min")?,
+            median: nanos("median")?,
+            max: nanos("max")?,
+        };
+        Ok(Some(((day, stage.to_string()), stats)))
+    })?;
+    Ok(rows.into_iter().flatten().collect())
+}
+
+/// Relative change of `now` against `before`, e.g. `+12.5%`.
+pub fn format_change(before: Duration, now: Duration) -> String {
+    let before = before.as_nanos() as f64;
+    if before == 0.0 {
+        return "n/a".to_string();
+    }
+    let change = (now.as_nanos() as f64 - before) / before * 100.0;
+    format!("{:+.1}%", change)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let mut samples: Vec<Duration> = [5, 1, 4, 2, 3]
+            .iter()
+            .map(|&x| Duration::from_millis(x))
+            .collect();
+        let s = Stats::from_samples(&mut samples);
+        assert_eq!(s.min, Duration::from_millis(1));
+        assert_eq!(s.median, Duration::from_millis(3));
+        assert_eq!(s.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let stats = Stats {
+            min: Duration::from_nanos(10),
+            median: Duration::from_nanos(20),
+            max: Duration::from_nanos(40),
+        };
+        let mut results = Results::new();
+        DayTimings {
+            day: 7,
+            stages: [stats, stats, stats],
+        }
+        .record(&mut results);
+        let saved = from_text(&to_text(&results)).unwrap();
+        assert_eq!(saved.len(), 3);
+        assert_eq!(saved[&(7, "part2".to_string())], stats);
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format_duration(Duration::from_nanos(850)), "850ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.5ms");
+        assert_eq!(format_duration(Duration::from_millis(2100)), "2.10s");
+        assert_eq!(
+            format_change(Duration::from_millis(200), Duration::from_millis(150)),
+            "-25.0%"
+        );
+    }
+}
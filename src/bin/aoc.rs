@@ -0,0 +1,1183 @@
+use advent_of_code_2020::answers::{parse_answers, to_toml, verify_day, Expected};
+use advent_of_code_2020::bench::{self, bench_day, format_change, format_duration, STAGES};
+use advent_of_code_2020::grid::Grid;
+use advent_of_code_2020::report::{
+    self, print_parse_error, DayReport, JsonReporter, ReportError, Reporter, TableReporter,
+    TextReporter,
+};
+use advent_of_code_2020::rng::Rng;
+use advent_of_code_2020::{
+    answers_path, bench_path, debug, info, input_path, log, read_input, solution, Part, SOLUTIONS,
+};
+use advent_of_code_2020::{aoc_01, aoc_02, aoc_03, aoc_04, csv};
+use std::env;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
+
+static USAGE: &str = r#"usage: aoc run <day|all> [options]
+       aoc verify [day|all]
+       aoc bench <day|all> [--runs <n>] [--file <path>]
+       aoc gen <day> [options]
+       aoc sum [options]
+       aoc passwords [options]
+       aoc corpus [options]
+       aoc slopes [options]
+       aoc route [options]
+       aoc passports [options]
+
+logging options, accepted by every command:
+    -q                only print answers and errors
+    -v, -vv           print debug or trace output from the days
+    --log-day <days>  only log from these days, e.g. `19` or `13,19`
+
+run options:
+    --part <1|2>      only solve one part
+    --input <path>... read the puzzle input from each <path> instead of
+                      inputs/input_XX; `-` reads stdin. Several inputs are
+                      shown as a table with a row per input
+    --example         run the examples from the puzzle text instead, and
+                      check their answers
+    --format <fmt>    `text` (default) or `json`, one object per day
+
+bench options:
+    --runs <n>        how many times to time each day (default 10)
+    --file <path>     where to save results and find the previous run
+                      (default target/aoc-bench.tsv)
+
+gen options:
+    --seed <n>        seed for the random input (default 0)
+    --size <n>        roughly how many items to generate (default 100)
+    --answers <path>  write the known answers to <path> as an answers.toml
+                      table instead of logging them
+
+sum options, for finding day 1 expenses that add up to a target:
+    --k <n>           how many entries to add up (default 2)
+    --any             look for subsets of any size instead of --k entries
+    --limit <n>       list at most <n> subsets with --any (default 10)
+    --target <n>      the sum to look for (default 2020)
+    --input <path>    read the expenses from <path> instead of inputs/input_01;
+                      `-` reads stdin
+
+passwords options, for checking a day 2 password database:
+    --policy <spec>   the policy for lines that don't start with their own:
+                      `count` or `positions` (the part 1 and part 2 readings
+                      of each line's numbers, default `count`), or one of
+                      `count <min>-<max> <letter>`, `positions <a>-<b>
+                      <letter>`, `no-repeat`, `distinct <n>`, `regex <re>`
+    --input <path>    read the database from <path> instead of inputs/input_02;
+                      `-` reads stdin
+    --report <fmt>    check every line on its own and report each one's
+                      result, as `text` or `csv`, instead of just counting
+                      valid passwords
+
+corpus options, for making labelled day 2 password databases:
+    --policy <p>      `count` or `positions`, the policy the labels are for
+                      (default count)
+    --count <n>       how many lines to make (default 1000)
+    --alphabet <abc>  the lowercase letters passwords use (default a-z)
+    --length <a>-<b>  password lengths, at least 2 (default 2-20)
+    --valid-ratio <r> the share of valid passwords (default 0.5)
+    --seed <n>        seed for the random corpus (default 0)
+    --labels <path>   write each line's number and label to <path> as CSV
+
+slopes options, for counting trees on the day 3 map:
+    --slope <r>,<d>   count trees going <r> right (negative for left) for
+                      every <d> down; may be repeated (default: part 2's five)
+    --sweep <r1>..<r2>,<d1>..<d2>
+                      try every slope in these inclusive ranges and report
+                      the ones hitting the fewest trees
+    --render          draw the map after each slope with its path marked,
+                      `O` for open squares and `X` for trees
+    --color           color the marks when drawing the map
+    --output <path>   draw the map into <path> instead of the terminal
+    --input <path>    read the map from <path> instead of inputs/input_03;
+                      `-` reads stdin
+
+route options, for the day 3 path from top to bottom hitting fewest trees:
+    --step <r>,<d>    a move the path may make, <r> right and <d> down; may
+                      be repeated (default: down, down-left and down-right)
+    --input <path>    as for slopes
+
+passports options, for checking day 4 passports:
+    --schema <path>   read the fields and their rules from <path> instead of
+                      the built-in copy of passport.schema
+    --input <path>    read the passports from <path> instead of
+                      inputs/input_04; `-` reads stdin
+    --report          list every passport's violations and the most common
+                      ones, instead of just counting valid passports
+    --export <fmt>    print every passport as `csv` or `json`, with a column
+                      per field and its validity under part 1 and the schema"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug)]
+struct RunArgs {
+    days: Vec<usize>,
+    part: Part,
+    inputs: Vec<PathBuf>,
+    example: bool,
+    format: Format,
+}
+
+fn parse_days(arg: &str) -> Result<Vec<usize>, String> {
+    if arg == "all" {
+        return Ok((1..=SOLUTIONS.len()).collect());
+    }
+    match arg.parse::<usize>() {
+        Ok(day) if day >= 1 && day <= SOLUTIONS.len() => Ok(vec![day]),
+        _ => Err(format!("invalid day: {}", arg)),
+    }
+}
+
+/// Strips the logging options out of `args` and applies them.
+fn parse_log_args(args: &mut Vec<String>) -> Result<(), String> {
+    let mut rest = Vec::new();
+    let mut iter = std::mem::take(args).into_iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-q" => log::set_verbosity(0),
+            "-v" => log::set_verbosity(log::Level::Debug as u8),
+            "-vv" => log::set_verbosity(log::Level::Trace as u8),
+            "--log-day" => {
+                let value = iter.next().ok_or("--log-day needs a value")?;
+                let days = value
+                    .split(',')
+                    .map(parse_days)
+                    .collect::<Result<Vec<_>, _>>()?;
+                log::set_days(&days.concat());
+            }
+            _ => rest.push(arg),
+        }
+    }
+    *args = rest;
+    Ok(())
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut iter = args.iter();
+    let days = parse_days(iter.next().ok_or("missing day")?)?;
+    let mut run_args = RunArgs {
+        days,
+        part: Part::Both,
+        inputs: Vec::new(),
+        example: false,
+        format: Format::Text,
+    };
+
+    let mut iter = iter.peekable();
+    while let Some(arg) = iter.next() {
+        if arg == "--input" {
+            while let Some(path) = iter.next_if(|x| *x == "-" || !x.starts_with('-')) {
+                run_args.inputs.push(PathBuf::from(path));
+            }
+            if run_args.inputs.is_empty() {
+                return Err("--input needs a value".to_string());
+            }
+            continue;
+        }
+        let mut value = || iter.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--part" => {
+                run_args.part = match value()?.as_str() {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    other => return Err(format!("invalid part: {}", other)),
+                }
+            }
+            "--format" => {
+                run_args.format = match value()?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    other => return Err(format!("invalid format: {}", other)),
+                }
+            }
+            "--example" => run_args.example = true,
+            _ => return Err(format!("unknown option: {}", arg)),
+        }
+    }
+
+    if !run_args.inputs.is_empty() && run_args.example {
+        return Err("--input and --example can't be used together".to_string());
+    }
+    if !run_args.inputs.is_empty() && run_args.days.len() != 1 {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    if run_args
+        .inputs
+        .iter()
+        .filter(|x| x.as_os_str() == "-")
+        .count()
+        > 1
+    {
+        return Err("stdin can only be read once".to_string());
+    }
+
+    Ok(run_args)
+}
+
+#[derive(Debug)]
+struct BenchArgs {
+    days: Vec<usize>,
+    runs: usize,
+    file: PathBuf,
+}
+
+#[derive(Debug)]
+struct GenArgs {
+    day: usize,
+    seed: u64,
+    size: usize,
+    answers: Option<PathBuf>,
+}
+
+fn parse_gen_args(args: &[String]) -> Result<GenArgs, String> {
+    let mut iter = args.iter();
+    let days = parse_days(iter.next().ok_or("missing day")?)?;
+    if days.len() != 1 {
+        return Err("gen takes a single day".to_string());
+    }
+    let mut gen_args = GenArgs {
+        day: days[0],
+        seed: 0,
+        size: 100,
+        answers: None,
+    };
+
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--seed" => {
+                let seed = value()?;
+                gen_args.seed = seed
+                    .parse()
+                    .map_err(|_| format!("invalid seed: {}", seed))?;
+            }
+            "--size" => {
+                let size = value()?;
+                gen_args.size = match size.parse::<usize>() {
+                    Ok(x) if x > 0 => x,
+                    _ => return Err(format!("invalid size: {}", size)),
+                }
+            }
+            "--answers" => gen_args.answers = Some(PathBuf::from(value()?)),
+            _ => return Err(format!("unknown option: {}", arg)),
+        }
+    }
+
+    Ok(gen_args)
+}
+
+#[derive(Debug)]
+struct SumArgs {
+    k: Option<usize>,
+    any: bool,
+    /// Only with `--any`; 10 if not given.
+    limit: Option<usize>,
+    target: i64,
+    input: PathBuf,
+}
+
+fn parse_sum_args(args: &[String]) -> Result<SumArgs, String> {
+    let mut sum_args = SumArgs {
+        k: None,
+        any: false,
+        limit: None,
+        target: 2020,
+        input: input_path(1),
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--k" => {
+                let k = value()?;
+                sum_args.k = Some(k.parse().map_err(|_| format!("invalid k: {}", k))?);
+            }
+            "--any" => sum_args.any = true,
+            "--limit" => {
+                let limit = value()?;
+                sum_args.limit = match limit.parse::<usize>() {
+                    Ok(x) if x > 0 => Some(x),
+                    _ => return Err(format!("invalid limit: {}", limit)),
+                }
+            }
+            "--target" => {
+                let target = value()?;
+                sum_args.target = target
+                    .parse()
+                    .map_err(|_| format!("invalid target: {}", target))?;
+            }
+            "--input" => sum_args.input = PathBuf::from(value()?),
+            _ => return Err(format!("unknown option: {}", arg)),
+        }
+    }
+
+    if sum_args.any && sum_args.k.is_some() {
+        return Err("--k and --any can't be used together".to_string());
+    }
+    if sum_args.limit.is_some() && !sum_args.any {
+        return Err("--limit needs --any".to_string());
+    }
+    Ok(sum_args)
+}
+
+struct PasswordArgs {
+    policy: aoc_02::RunPolicy,
+    input: PathBuf,
+    report: Option<ReportFormat>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReportFormat {
+    Text,
+    Csv,
+}
+
+fn parse_password_args(args: &[String]) -> Result<PasswordArgs, String> {
+    let mut password_args = PasswordArgs {
+        policy: aoc_02::RunPolicy::Count,
+        input: input_path(2),
+        report: None,
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--policy" => {
+                let spec = value()?;
+                password_args.policy = aoc_02::RunPolicy::parse(spec)
+                    .map_err(|e| format!("invalid policy `{}`: {}", spec, e.kind))?;
+            }
+            "--input" => password_args.input = PathBuf::from(value()?),
+            "--report" => {
+                password_args.report = match value()?.as_str() {
+                    "text" => Some(ReportFormat::Text),
+                    "csv" => Some(ReportFormat::Csv),
+                    other => return Err(format!("invalid report format: {}", other)),
+                }
+            }
+            _ => return Err(format!("unknown option: {}", arg)),
+        }
+    }
+
+    Ok(password_args)
+}
+
+#[derive(Debug)]
+struct CorpusArgs {
+    options: aoc_02::CorpusOptions,
+    count: usize,
+    seed: u64,
+    labels: Option<PathBuf>,
+}
+
+fn parse_corpus_args(args: &[String]) -> Result<CorpusArgs, String> {
+    let mut corpus_args = CorpusArgs {
+        options: aoc_02::CorpusOptions::default(),
+        count: 1000,
+        seed: 0,
+        labels: None,
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--policy" => {
+                corpus_args.options.policy = match value()?.as_str() {
+                    "count" => aoc_02::CorpusPolicy::Count,
+                    "positions" => aoc_02::CorpusPolicy::Positions,
+                    other => return Err(format!("invalid corpus policy: {}", other)),
+                }
+            }
+            "--count" => {
+                let count = value()?;
+                corpus_args.count = count
+                    .parse()
+                    .map_err(|_| format!("invalid count: {}", count))?;
+            }
+            "--alphabet" => corpus_args.options.alphabet = value()?.chars().collect(),
+            "--length" => {
+                let length = value()?;
+                let bounds = length
+                    .split_once('-')
+                    .and_then(|(a, b)| Some((a.parse().ok()?, b.parse().ok()?)))
+                    .ok_or_else(|| format!("invalid length: {}", length))?;
+                corpus_args.options.min_len = bounds.0;
+                corpus_args.options.max_len = bounds.1;
+            }
+            "--valid-ratio" => {
+                let ratio = value()?;
+                corpus_args.options.valid_ratio = ratio
+                    .parse()
+                    .map_err(|_| format!("invalid ratio: {}", ratio))?;
+            }
+            "--seed" => {
+                let seed = value()?;
+                corpus_args.seed = seed
+                    .parse()
+                    .map_err(|_| format!("invalid seed: {}", seed))?;
+            }
+            "--labels" => corpus_args.labels = Some(PathBuf::from(value()?)),
+            _ => return Err(format!("unknown option: {}", arg)),
+        }
+    }
+
+    match corpus_args.options.problem() {
+        Some(problem) => Err(problem),
+        None => Ok(corpus_args),
+    }
+}
+
+#[derive(Debug)]
+struct SlopeArgs {
+    slopes: Vec<aoc_03::Slope>,
+    sweep: Option<(RangeInclusive<i64>, RangeInclusive<usize>)>,
+    render: bool,
+    color: bool,
+    output: Option<PathBuf>,
+    input: PathBuf,
+}
+
+/// Parses `<a>,<b>`, e.g. a slope or a pair of ranges.
+fn parse_pair<A: FromStr, B: FromStr>(text: &str) -> Option<(A, B)> {
+    let (a, b) = text.split_once(',')?;
+    Some((a.trim().parse().ok()?, b.trim().parse().ok()?))
+}
+
+/// Parses an inclusive range written `<a>..<b>`.
+fn parse_range<T: FromStr + PartialOrd>(text: &str) -> Option<RangeInclusive<T>> {
+    let (a, b) = text.split_once("..")?;
+    let (a, b) = (a.trim().parse().ok()?, b.trim().parse().ok()?);
+    if a <= b {
+        Some(a..=b)
+    } else {
+        None
+    }
+}
+
+fn parse_slope_args(args: &[String]) -> Result<SlopeArgs, String> {
+    let mut slope_args = SlopeArgs {
+        slopes: Vec::new(),
+        sweep: None,
+        render: false,
+        color: false,
+        output: None,
+        input: input_path(3),
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--slope" => {
+                let slope = value()?;
+                match parse_pair(slope) {
+                    Some((right, down)) if down > 0 => {
+                        slope_args.slopes.push(aoc_03::Slope::new(right, down))
+                    }
+                    _ => return Err(format!("invalid slope: {}", slope)),
+                }
+            }
+            "--sweep" => {
+                let sweep = value()?;
+                slope_args.sweep = match parse_pair::<String, String>(sweep) {
+                    Some((right, down)) => match (parse_range(&right), parse_range(&down)) {
+                        (Some(right), Some(down)) if *down.start() > 0 => Some((right, down)),
+                        _ => return Err(format!("invalid sweep: {}", sweep)),
+                    },
+                    None => return Err(format!("invalid sweep: {}", sweep)),
+                }
+            }
+            "--render" => slope_args.render = true,
+            "--color" => slope_args.color = true,
+            "--output" => slope_args.output = Some(PathBuf::from(value()?)),
+            "--input" => slope_args.input = PathBuf::from(value()?),
+            _ => return Err(format!("unknown option: {}", arg)),
+        }
+    }
+
+    if slope_args.sweep.is_some() && !slope_args.slopes.is_empty() {
+        return Err("--slope and --sweep can't be used together".to_string());
+    }
+    slope_args.render |= slope_args.color || slope_args.output.is_some();
+    if slope_args.sweep.is_some() && slope_args.render {
+        return Err("--sweep and --render can't be used together".to_string());
+    }
+    if slope_args.slopes.is_empty() {
+        slope_args.slopes = aoc_03::SLOPES.to_vec();
+    }
+    Ok(slope_args)
+}
+
+#[derive(Debug)]
+struct RouteArgs {
+    steps: Vec<(i64, i64)>,
+    input: PathBuf,
+}
+
+fn parse_route_args(args: &[String]) -> Result<RouteArgs, String> {
+    let mut route_args = RouteArgs {
+        steps: Vec::new(),
+        input: input_path(3),
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--step" => {
+                let step = value()?;
+                match parse_pair(step) {
+                    Some(step) if step != (0, 0) => route_args.steps.push(step),
+                    _ => return Err(format!("invalid step: {}", step)),
+                }
+            }
+            "--input" => route_args.input = PathBuf::from(value()?),
+            _ => return Err(format!("unknown option: {}", arg)),
+        }
+    }
+
+    if route_args.steps.is_empty() {
+        route_args.steps = vec![(0, 1), (-1, 1), (1, 1)];
+    }
+    Ok(route_args)
+}
+
+#[derive(Debug)]
+struct PassportArgs {
+    schema: Option<PathBuf>,
+    input: PathBuf,
+    report: bool,
+    export: Option<ExportFormat>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportFormat {
+    Csv,
+    Json,
+}
+
+fn parse_passport_args(args: &[String]) -> Result<PassportArgs, String> {
+    let mut passport_args = PassportArgs {
+        schema: None,
+        input: input_path(4),
+        report: false,
+        export: None,
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--schema" => passport_args.schema = Some(PathBuf::from(value()?)),
+            "--input" => passport_args.input = PathBuf::from(value()?),
+            "--report" => passport_args.report = true,
+            "--export" => {
+                passport_args.export = match value()?.as_str() {
+                    "csv" => Some(ExportFormat::Csv),
+                    "json" => Some(ExportFormat::Json),
+                    other => return Err(format!("invalid export format: {}", other)),
+                }
+            }
+            _ => return Err(format!("unknown option: {}", arg)),
+        }
+    }
+
+    if passport_args.report && passport_args.export.is_some() {
+        return Err("--report and --export can't be used together".to_string());
+    }
+    Ok(passport_args)
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut iter = args.iter();
+    let days = parse_days(iter.next().ok_or("missing day")?)?;
+    let mut bench_args = BenchArgs {
+        days,
+        runs: 10,
+        file: bench_path(),
+    };
+
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--runs" => {
+                let runs = value()?;
+                bench_args.runs = match runs.parse::<usize>() {
+                    Ok(x) if x > 0 => x,
+                    _ => return Err(format!("invalid run count: {}", runs)),
+                }
+            }
+            "--file" => bench_args.file = PathBuf::from(value()?),
+            _ => return Err(format!("unknown option: {}", arg)),
+        }
+    }
+
+    Ok(bench_args)
+}
+
+/// Whether the answers in `report` match the ones `expected` records,
+/// explaining any that don't on stderr.
+fn check_answers(report: &DayReport, expected: &Expected) -> bool {
+    let mut ok = true;
+    for p in report.parts.iter() {
+        let answer = if p.part == 1 {
+            &expected.part1
+        } else {
+            &expected.part2
+        };
+        match answer {
+            Some(answer) if *answer != p.answer => {
+                eprintln!(
+                    "day {:02} {} part {}: expected {}, got {}",
+                    report.day,
+                    report.path.display(),
+                    p.part,
+                    answer,
+                    p.answer
+                );
+                ok = false;
+            }
+            _ => (),
+        }
+    }
+    ok
+}
+
+fn run_examples(run_args: &RunArgs, reporter: &mut dyn Reporter) -> bool {
+    let mut ok = true;
+    for &day in run_args.days.iter() {
+        let examples = solution(day).unwrap().examples_dyn();
+        if examples.is_empty() {
+            info!("day {:02}: no examples", day);
+        }
+        for (i, example) in examples.iter().enumerate() {
+            let path = PathBuf::from(format!("example {}", i + 1));
+            let solution = example.solution(day);
+            let report = report::solve_with(solution, day, &path, example.input, run_args.part);
+            ok &= report.error.is_none() && check_answers(&report, &example.expected);
+            reporter.report(&report, example.input);
+        }
+    }
+    reporter.finish();
+    ok
+}
+
+fn run(run_args: &RunArgs) -> bool {
+    let several = if run_args.example {
+        run_args.days.len() == 1 && solution(run_args.days[0]).unwrap().examples_dyn().len() > 1
+    } else {
+        run_args.inputs.len() > 1
+    };
+    let mut reporter: Box<dyn Reporter> = match run_args.format {
+        Format::Text if several => Box::new(TableReporter::new(run_args.part)),
+        Format::Text => Box::new(TextReporter {
+            headers: run_args.days.len() > 1,
+            inputs: run_args.example,
+        }),
+        Format::Json => Box::new(JsonReporter),
+    };
+    if run_args.example {
+        return run_examples(run_args, reporter.as_mut());
+    }
+
+    let mut ok = true;
+    for &day in run_args.days.iter() {
+        let paths = if run_args.inputs.is_empty() {
+            vec![input_path(day)]
+        } else {
+            run_args.inputs.clone()
+        };
+        for path in paths.iter() {
+            let (report, file_contents) = match read_input(path) {
+                Ok(x) => (report::solve(day, path, &x, run_args.part), x),
+                Err(e) => (
+                    DayReport::failed(day, path, ReportError::Read(e.to_string())),
+                    String::new(),
+                ),
+            };
+            ok &= report.error.is_none();
+            reporter.report(&report, &file_contents);
+        }
+    }
+    reporter.finish();
+    ok
+}
+
+/// Checks each day against `answers.toml`, returning false if any answer
+/// changed or a day couldn't be solved.
+fn verify(days: &[usize]) -> bool {
+    let path = answers_path();
+    let answers = match fs::read_to_string(&path) {
+        Ok(text) => match parse_answers(&text) {
+            Ok(x) => x,
+            Err(e) => {
+                print_parse_error("answers", &path, &text, &e);
+                return false;
+            }
+        },
+        Err(e) => {
+            eprintln!("couldn't read {}: {}", path.display(), e);
+            return false;
+        }
+    };
+
+    let mut ok = true;
+    for &day in days.iter() {
+        let expected = match answers.get(&day) {
+            Some(x) => x,
+            None => {
+                if days.len() == 1 {
+                    info!("day {:02}: no recorded answers", day);
+                }
+                continue;
+            }
+        };
+        let path = input_path(day);
+        let file_contents = match fs::read_to_string(&path) {
+            Ok(x) => x,
+            Err(e) => {
+                eprintln!("day {:02}: couldn't read {}: {}", day, path.display(), e);
+                ok = false;
+                continue;
+            }
+        };
+        match verify_day(day, &file_contents, expected) {
+            Ok(checks) => {
+                for c in checks.iter() {
+                    if c.ok() {
+                        println!("day {:02} part {}: ok", day, c.part);
+                    } else {
+                        println!(
+                            "day {:02} part {}: expected {}, got {}",
+                            day, c.part, c.expected, c.actual
+                        );
+                        ok = false;
+                    }
+                }
+            }
+            Err(e) => {
+                print_parse_error(&format!("day {:02}", day), &path, &file_contents, &e);
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+/// Prints a random input for a day to stdout, along with its answers where
+/// the generator knows them.
+fn gen(gen_args: &GenArgs) -> bool {
+    let day = gen_args.day;
+    let mut rng = Rng::new(gen_args.seed);
+    let generated = match solution(day).unwrap().generate_dyn(&mut rng, gen_args.size) {
+        Some(x) => x,
+        None => {
+            eprintln!("day {:02}: no generator", day);
+            return false;
+        }
+    };
+    print!("{}", generated.input);
+
+    match &gen_args.answers {
+        Some(path) => {
+            if let Err(e) = fs::write(path, to_toml(day, &generated.expected)) {
+                eprintln!("couldn't save {}: {}", path.display(), e);
+                return false;
+            }
+        }
+        None => {
+            let parts = [&generated.expected.part1, &generated.expected.part2];
+            for (i, answer) in parts.iter().enumerate() {
+                match answer {
+                    Some(answer) => info!("day {:02} part {}: {}", day, i + 1, answer),
+                    None => info!("day {:02} part {}: unknown", day, i + 1),
+                }
+            }
+        }
+    }
+    true
+}
+
+/// Reads an input for one of the day tools, explaining on stderr if it
+/// can't.
+fn read_tool_input(path: &Path) -> Option<String> {
+    match read_input(path) {
+        Ok(x) => Some(x),
+        Err(e) => {
+            eprintln!("couldn't read {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+/// Prints every combination of `k` expenses that adds up to the target, or
+/// up to `limit` subsets of any size with `--any`, each with its product.
+fn sum(sum_args: &SumArgs) -> bool {
+    let path = &sum_args.input;
+    let limit = sum_args.limit.unwrap_or(10);
+    let text = match read_tool_input(path) {
+        Some(x) => x,
+        None => return false,
+    };
+    let numbers = match aoc_01::parse(&text) {
+        Ok(x) => x,
+        Err(e) => {
+            print_parse_error("day 01", path, &text, &e);
+            return false;
+        }
+    };
+
+    let mut found = if sum_args.any {
+        if numbers.iter().any(|&x| x < 0) {
+            eprintln!(
+                "{}: --any needs expenses that aren't negative",
+                path.display()
+            );
+            return false;
+        }
+        // One extra tells us whether the limit cut anything off.
+        aoc_01::find_subset_sums(&numbers, sum_args.target, limit.saturating_add(1))
+    } else {
+        aoc_01::find_k_sum(&numbers, sum_args.k.unwrap_or(2), sum_args.target)
+    };
+    let truncated = sum_args.any && found.len() > limit;
+    found.truncate(if sum_args.any { limit } else { found.len() });
+
+    for combination in found.iter() {
+        let terms: Vec<String> = combination.iter().map(|x| x.to_string()).collect();
+        println!(
+            "{} = {} (product {})",
+            terms.join(" + "),
+            sum_args.target,
+            aoc_01::product(combination)
+        );
+    }
+    if found.is_empty() && sum_args.any {
+        info!("no subset adds up to {}", sum_args.target);
+    } else if found.is_empty() {
+        let k = sum_args.k.unwrap_or(2);
+        info!("no {} entries add up to {}", k, sum_args.target);
+    }
+    if truncated {
+        info!("stopped after {} subsets; --limit shows more", limit);
+    }
+    true
+}
+
+/// Prints how many passwords in the database follow their policy, or a
+/// report on every line.
+fn passwords(password_args: &PasswordArgs) -> bool {
+    let path = &password_args.input;
+    let text = match read_tool_input(path) {
+        Some(x) => x,
+        None => return false,
+    };
+    if let Some(format) = password_args.report {
+        let reports = aoc_02::validate(&text, &password_args.policy);
+        match format {
+            ReportFormat::Text => print!("{}", aoc_02::report_text(&reports)),
+            ReportFormat::Csv => print!("{}", aoc_02::report_csv(&reports)),
+        }
+        let count =
+            |f: fn(&aoc_02::Status) -> bool| reports.iter().filter(|r| f(&r.status)).count();
+        info!(
+            "{} valid, {} invalid, {} errors",
+            count(|s| *s == aoc_02::Status::Valid),
+            count(|s| matches!(s, aoc_02::Status::Invalid(_))),
+            count(|s| matches!(s, aoc_02::Status::Error(_)))
+        );
+        return true;
+    }
+    let entries = match aoc_02::parse(&text) {
+        Ok(x) => x,
+        Err(e) => {
+            print_parse_error("day 02", path, &text, &e);
+            return false;
+        }
+    };
+    let valid = aoc_02::count_valid(&password_args.policy, &entries);
+    println!("{} of {} passwords are valid", valid, entries.len());
+    true
+}
+
+/// Prints a password database whose lines are known to be valid or not,
+/// saving those labels if asked.
+fn corpus(corpus_args: &CorpusArgs) -> bool {
+    let mut rng = Rng::new(corpus_args.seed);
+    let corpus = aoc_02::generate_corpus(&mut rng, &corpus_args.options, corpus_args.count);
+    let mut labels = csv::row(&["line", "valid"]);
+    for (i, (line, valid)) in corpus.iter().enumerate() {
+        println!("{}", line);
+        labels += &csv::row(&[(i + 1).to_string(), valid.to_string()]);
+    }
+
+    let valid = corpus.iter().filter(|(_, valid)| *valid).count();
+    info!("{} of {} passwords are valid", valid, corpus.len());
+    if let Some(path) = &corpus_args.labels {
+        if let Err(e) = fs::write(path, labels) {
+            eprintln!("couldn't save {}: {}", path.display(), e);
+            return false;
+        }
+    }
+    true
+}
+
+/// Prints the trees hit along each slope and their product, or the slopes
+/// from a sweep that hit the fewest. With `--render`, each slope's path is
+/// drawn on the map too.
+fn slopes(slope_args: &SlopeArgs) -> bool {
+    let map = match read_map(&slope_args.input) {
+        Some(x) => x,
+        None => return false,
+    };
+
+    if let Some((right, down)) = &slope_args.sweep {
+        let results = aoc_03::sweep(&map, right.clone(), down.clone());
+        for (slope, trees) in results.iter() {
+            debug!("{}: {} trees", slope, trees);
+        }
+        let fewest = results[0].1;
+        println!("fewest trees: {}", fewest);
+        for (slope, _) in results.iter().take_while(|(_, trees)| *trees == fewest) {
+            println!("{}", slope);
+        }
+        return true;
+    }
+
+    let mut product: u128 = 1;
+    let mut drawn = String::new();
+    for &slope in slope_args.slopes.iter() {
+        let trees = aoc_03::traverse(&map, slope);
+        product = product.saturating_mul(trees as u128);
+        println!("{}: {} trees", slope, trees);
+        if slope_args.render {
+            let path = aoc_03::slope_path(&map, slope);
+            let picture = aoc_03::render(&map, &path, slope_args.color);
+            match slope_args.output {
+                Some(_) => drawn += &format!("{}: {} trees\n{}\n", slope, trees, picture),
+                None => println!("{}", picture),
+            }
+        }
+    }
+    if slope_args.slopes.len() > 1 {
+        println!("product: {}", product);
+    }
+    if let Some(path) = &slope_args.output {
+        if let Err(e) = fs::write(path, drawn) {
+            eprintln!("couldn't save {}: {}", path.display(), e);
+            return false;
+        }
+        info!("drew the map into {}", path.display());
+    }
+    true
+}
+
+/// Reads the day 3 map for `slopes` and `route`.
+fn read_map(path: &Path) -> Option<Grid<bool>> {
+    let text = read_tool_input(path)?;
+    match aoc_03::parse(&text) {
+        Ok(x) => Some(x),
+        Err(e) => {
+            print_parse_error("day 03", path, &text, &e);
+            None
+        }
+    }
+}
+
+/// Prints the fewest trees any path down the map can hit, and the squares
+/// along one such path.
+fn route(route_args: &RouteArgs) -> bool {
+    let map = match read_map(&route_args.input) {
+        Some(x) => x,
+        None => return false,
+    };
+    match aoc_03::least_trees(&map, &route_args.steps) {
+        Some(route) => {
+            println!("fewest trees: {}", route.trees);
+            for (x, y) in route.path.iter() {
+                println!("{},{}", x, y);
+            }
+            true
+        }
+        None => {
+            eprintln!(
+                "{}: no route reaches the bottom",
+                route_args.input.display()
+            );
+            false
+        }
+    }
+}
+
+/// Prints how many passports have every required field, and how many also
+/// follow the schema's rules, or with `--report` why each one doesn't. With
+/// `--export` it prints the passports themselves.
+fn passports(passport_args: &PassportArgs) -> bool {
+    let schema = match &passport_args.schema {
+        Some(path) => {
+            let text = match read_tool_input(path) {
+                Some(x) => x,
+                None => return false,
+            };
+            match aoc_04::Schema::parse(&text) {
+                Ok(x) => x,
+                Err(e) => {
+                    print_parse_error("schema", path, &text, &e);
+                    return false;
+                }
+            }
+        }
+        None => aoc_04::Schema::default(),
+    };
+    let text = match read_tool_input(&passport_args.input) {
+        Some(x) => x,
+        None => return false,
+    };
+    let passports = match aoc_04::parse_with_warnings(&text) {
+        Ok((passports, warnings)) => {
+            for w in warnings.iter() {
+                print_parse_error("warning", &passport_args.input, &text, w);
+            }
+            passports
+        }
+        Err(e) => {
+            print_parse_error("day 04", &passport_args.input, &text, &e);
+            return false;
+        }
+    };
+
+    if let Some(format) = passport_args.export {
+        match format {
+            ExportFormat::Csv => print!("{}", aoc_04::export_csv(&schema, &passports)),
+            ExportFormat::Json => print!("{}", aoc_04::export_json(&schema, &passports)),
+        }
+        return true;
+    }
+
+    if passport_args.report {
+        print!(
+            "{}",
+            aoc_04::report_text(&aoc_04::validate(&schema, &passports))
+        );
+        return true;
+    }
+
+    let complete = passports.iter().filter(|x| schema.complete(x)).count();
+    let valid = passports.iter().filter(|x| schema.valid(x)).count();
+    println!("complete: {}", complete);
+    println!("valid: {}", valid);
+    true
+}
+
+/// Times each day, prints a table compared against the previous results in
+/// the bench file, and then updates that file.
+fn bench(bench_args: &BenchArgs) -> bool {
+    let previous = match fs::read_to_string(&bench_args.file) {
+        Ok(text) => match bench::from_text(&text) {
+            Ok(x) => x,
+            Err(e) => {
+                print_parse_error("bench", &bench_args.file, &text, &e);
+                return false;
+            }
+        },
+        Err(_) => bench::Results::new(),
+    };
+    let mut results = previous.clone();
+
+    let mut ok = true;
+    println!(
+        "{:>3}  {:<5} {:>10} {:>10} {:>10}  {:>8}",
+        "day", "stage", "min", "median", "max", "vs last"
+    );
+    for &day in bench_args.days.iter() {
+        let path = input_path(day);
+        let file_contents = match fs::read_to_string(&path) {
+            Ok(x) => x,
+            Err(e) => {
+                eprintln!("day {:02}: couldn't read {}: {}", day, path.display(), e);
+                ok = false;
+                continue;
+            }
+        };
+        let timings = match bench_day(day, &file_contents, bench_args.runs) {
+            Ok(x) => x,
+            Err(e) => {
+                print_parse_error(&format!("day {:02}", day), &path, &file_contents, &e);
+                ok = false;
+                continue;
+            }
+        };
+
+        for (stage, stats) in STAGES.iter().zip(timings.stages.iter()) {
+            let change = previous
+                .get(&(day, stage.to_string()))
+                .map(|before| format_change(before.median, stats.median))
+                .unwrap_or_default();
+            println!(
+                "{:>3}  {:<5} {:>10} {:>10} {:>10}  {:>8}",
+                day,
+                stage,
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max),
+                change
+            );
+        }
+        timings.record(&mut results);
+    }
+
+    match fs::write(&bench_args.file, bench::to_text(&results)) {
+        Ok(()) => info!("saved results to {}", bench_args.file.display()),
+        Err(e) => {
+            eprintln!("couldn't save {}: {}", bench_args.file.display(), e);
+            ok = false;
+        }
+    }
+    ok
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    if let Err(e) = parse_log_args(&mut args) {
+        eprintln!("{}\n\n{}", e, USAGE);
+        process::exit(2);
+    }
+    let result = match args.first().map(String::as_str) {
+        Some("run") => parse_run_args(&args[1..]).map(|x| run(&x)),
+        Some("bench") => parse_bench_args(&args[1..]).map(|x| bench(&x)),
+        Some("gen") => parse_gen_args(&args[1..]).map(|x| gen(&x)),
+        Some("sum") => parse_sum_args(&args[1..]).map(|x| sum(&x)),
+        Some("passwords") => parse_password_args(&args[1..]).map(|x| passwords(&x)),
+        Some("corpus") => parse_corpus_args(&args[1..]).map(|x| corpus(&x)),
+        Some("slopes") => parse_slope_args(&args[1..]).map(|x| slopes(&x)),
+        Some("route") => parse_route_args(&args[1..]).map(|x| route(&x)),
+        Some("passports") => parse_passport_args(&args[1..]).map(|x| passports(&x)),
+        Some("verify") => match &args[1..] {
+            [] => parse_days("all").map(|x| verify(&x)),
+            [day] => parse_days(day).map(|x| verify(&x)),
+            _ => Err("verify takes at most one day".to_string()),
+        },
+        Some(other) => Err(format!("unknown command: {}", other)),
+        None => Err("missing command".to_string()),
+    };
+
+    match result {
+        Ok(true) => (),
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    }
+}
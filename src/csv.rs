@@ -0,0 +1,30 @@
+//! Just enough CSV writing for the day tools' exports, following RFC 4180.
+
+/// Quotes `s` if it holds a comma, quote or line break, doubling any quotes.
+pub fn field(s: &str) -> String {
+    if s.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// One record, with its line break.
+pub fn row<S: AsRef<str>>(fields: &[S]) -> String {
+    let fields: Vec<String> = fields.iter().map(|f| field(f.as_ref())).collect();
+    fields.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row() {
+        assert_eq!(row(&["a", "b c", ""]), "a,b c,\n");
+        assert_eq!(
+            row(&["1,2", "say \"hi\"", "x\ny"]),
+            "\"1,2\",\"say \"\"hi\"\"\",\"x\ny\"\n"
+        );
+    }
+}
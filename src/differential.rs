@@ -0,0 +1,128 @@
+//! Differential testing: runs two implementations of the same step on random
+//! inputs and, when they disagree, shrinks the input down to a small
+//! counterexample.
+//!
+//! Days that keep more than one implementation check them against each other
+//! in their tests. By default each comparison runs a few hundred cases from
+//! seed 0; `AOC_DIFF_CASES` and `AOC_DIFF_SEED` run more or different ones,
+//! e.g. `AOC_DIFF_CASES=100000 cargo test differential`.
+
+use crate::rng::Rng;
+use std::env;
+use std::fmt::{self, Debug};
+
+const DEFAULT_CASES: u64 = 300;
+
+/// Two implementations gave different outputs for `input`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Disagreement<I, O> {
+    /// The seed whose input first disagreed, before shrinking.
+    pub seed: u64,
+    pub input: I,
+    pub left: O,
+    pub right: O,
+}
+
+impl<I: Debug, O: Debug> fmt::Display for Disagreement<I, O> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "seed {}, shrunk to {:?}: {:?} vs {:?}",
+            self.seed, self.input, self.left, self.right
+        )
+    }
+}
+
+fn env_num(name: &str, default: u64) -> u64 {
+    match env::var(name) {
+        Ok(value) => value
+            .parse()
+            .unwrap_or_else(|_| panic!("{} should be a number, not `{}`", name, value)),
+        Err(_) => default,
+    }
+}
+
+/// Runs `left` and `right` on an input from `generate` for each seed, and
+/// returns the first disagreement, shrunk with `shrink`.
+pub fn compare<I, O>(
+    generate: impl Fn(&mut Rng) -> I,
+    shrink: impl Fn(&I) -> Vec<I>,
+    left: impl Fn(&I) -> O,
+    right: impl Fn(&I) -> O,
+) -> Result<(), Disagreement<I, O>>
+where
+    O: PartialEq,
+{
+    let first = env_num("AOC_DIFF_SEED", 0);
+    let cases = env_num("AOC_DIFF_CASES", DEFAULT_CASES);
+    for seed in first..first.saturating_add(cases) {
+        let input = generate(&mut Rng::new(seed));
+        if left(&input) != right(&input) {
+            let input = minimize(input, &shrink, |x| left(x) != right(x));
+            return Err(Disagreement {
+                seed,
+                left: left(&input),
+                right: right(&input),
+                input,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Replaces `input` with the first of its shrinks that still `fails` until
+/// none do. Shrinks must be strictly smaller for this to finish.
+pub fn minimize<I>(mut input: I, shrink: impl Fn(&I) -> Vec<I>, fails: impl Fn(&I) -> bool) -> I {
+    while let Some(smaller) = shrink(&input).into_iter().find(|x| fails(x)) {
+        input = smaller;
+    }
+    input
+}
+
+/// `items` with its back or front half dropped, then with each single item
+/// dropped.
+pub fn shrink_vec<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut shrinks = Vec::new();
+    if items.len() > 1 {
+        let half = items.len() / 2;
+        shrinks.push(items[..half].to_vec());
+        shrinks.push(items[half..].to_vec());
+    }
+    for i in 0..items.len() {
+        let mut shrunk = items.to_vec();
+        shrunk.remove(i);
+        shrinks.push(shrunk);
+    }
+    shrinks
+}
+
+/// `shrink_vec` over the characters of `s`.
+pub fn shrink_string(s: &str) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    shrink_vec(&chars)
+        .into_iter()
+        .map(|c| c.into_iter().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare() {
+        let generate = |rng: &mut Rng| -> Vec<i64> { (0..20).map(|_| rng.range(0, 9)).collect() };
+        let sum = |v: &Vec<i64>| v.iter().sum::<i64>();
+        let skips_sevens = |v: &Vec<i64>| v.iter().filter(|&&x| x != 7).sum::<i64>();
+        let d = compare(generate, |v| shrink_vec(v), sum, skips_sevens).unwrap_err();
+        assert_eq!(d.input, vec![7]);
+        assert_eq!((d.left, d.right), (7, 0));
+        assert!(compare(generate, |v| shrink_vec(v), sum, sum).is_ok());
+    }
+
+    #[test]
+    fn test_shrink_string() {
+        assert_eq!(shrink_string("abc"), vec!["a", "bc", "bc", "ac", "ab"]);
+        assert!(shrink_string("").is_empty());
+    }
+}
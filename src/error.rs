@@ -0,0 +1,152 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// What went wrong while reading a puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidNumber(String),
+    UnexpectedChar(char),
+    /// A token that isn't one of the names the day knows about, e.g. an
+    /// opcode or a passport key.
+    Unknown {
+        what: &'static str,
+        token: String,
+    },
+    /// Something required ran out before it was found.
+    Missing(&'static str),
+    Invalid(String),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidNumber(s) => write!(f, "invalid number `{}`", s),
+            ErrorKind::UnexpectedChar(c) => write!(f, "unexpected character `{}`", c),
+            ErrorKind::Unknown { what, token } => write!(f, "unknown {} `{}`", what, token),
+            ErrorKind::Missing(what) => write!(f, "missing {}", what),
+            ErrorKind::Invalid(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+/// A parse error, located by 1-based line and column in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+/// Byte offset of `token` in `src`, or the end of `src` if `token` isn't a
+/// slice of it.
+fn offset_of(src: &str, token: &str) -> usize {
+    let start = src.as_ptr() as usize;
+    let pos = token.as_ptr() as usize;
+    if pos >= start && pos <= start + src.len() {
+        pos - start
+    } else {
+        src.len()
+    }
+}
+
+fn position(src: &str, offset: usize) -> (usize, usize) {
+    let before = &src[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+impl AocError {
+    pub fn new(line: usize, column: usize, kind: ErrorKind) -> AocError {
+        AocError { line, column, kind }
+    }
+
+    /// An error pointing at `token`, which should be a slice of `src`.
+    pub fn at(src: &str, token: &str, kind: ErrorKind) -> AocError {
+        let (line, column) = position(src, offset_of(src, token));
+        AocError::new(line, column, kind)
+    }
+
+    pub fn at_end(src: &str, kind: ErrorKind) -> AocError {
+        AocError::at(src, &src[src.len()..], kind)
+    }
+
+    /// Re-locates an error found while parsing `inner` so that it is relative
+    /// to `outer`, which `inner` is a slice of.
+    pub fn within(self, outer: &str, inner: &str) -> AocError {
+        let (line, column) = position(outer, offset_of(outer, inner));
+        AocError {
+            line: self.line + line - 1,
+            column: if self.line == 1 {
+                self.column + column - 1
+            } else {
+                self.column
+            },
+            kind: self.kind,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl std::error::Error for AocError {}
+
+/// Parses `token`, a slice of `src`, as a number.
+pub fn parse_num<T: FromStr>(src: &str, token: &str) -> Result<T> {
+    token
+        .parse()
+        .map_err(|_| AocError::at(src, token, ErrorKind::InvalidNumber(token.to_string())))
+}
+
+/// Unwraps a token that has to be there, reporting the end of `src` if not.
+pub fn required<'a>(src: &str, token: Option<&'a str>, what: &'static str) -> Result<&'a str> {
+    token.ok_or_else(|| AocError::at_end(src, ErrorKind::Missing(what)))
+}
+
+/// Runs `f` over every non-empty line of `input`, locating any error within
+/// `input`.
+pub fn parse_lines<'a, T, F>(input: &'a str, mut f: F) -> Result<Vec<T>>
+where
+    F: FnMut(&'a str) -> Result<T>,
+{
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| f(line).map_err(|e| e.within(input, line)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location() {
+        let input = "12\n3x4\n56";
+        let err = parse_lines(input, |line| {
+            let token = &line[1..];
+            parse_num::<i32>(line, token)
+        })
+        .unwrap_err();
+        assert_eq!(
+            err,
+            AocError::new(2, 2, ErrorKind::InvalidNumber("x4".to_string()))
+        );
+        assert_eq!(err.to_string(), "line 2, column 2: invalid number `x4`");
+
+        let err = required(input, None, "seat").unwrap_err();
+        assert_eq!(err, AocError::new(3, 3, ErrorKind::Missing("seat")));
+    }
+}
@@ -0,0 +1,309 @@
+use crate::error::{parse_lines, AocError, ErrorKind, Result};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Offsets to the eight cells around a cell.
+pub static NEIGHBORS: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Offsets to the four cells sharing an edge with a cell.
+pub static ORTHOGONAL_NEIGHBORS: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// A rectangular grid stored row by row, indexed by `(x, y)` with the origin
+/// at the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "cells don't fill the grid");
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parses one row per non-empty line, converting each character with
+    /// `cell`. Characters `cell` rejects and ragged rows are errors.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        let rows = parse_lines(input, |line| {
+            line.char_indices()
+                .map(|(i, c)| {
+                    cell(c)
+                        .ok_or_else(|| AocError::at(line, &line[i..], ErrorKind::UnexpectedChar(c)))
+                })
+                .collect::<Result<Vec<_>>>()
+                .map(|row| (line, row))
+        })?;
+        let width = match rows.first() {
+            Some((_, row)) => row.len(),
+            None => return Err(AocError::at_end(input, ErrorKind::Missing("grid"))),
+        };
+        if let Some((line, _)) = rows.iter().find(|(_, row)| row.len() != width) {
+            return Err(AocError::at(
+                input,
+                line,
+                ErrorKind::Invalid(format!("expected a row of width {}", width)),
+            ));
+        }
+        let height = rows.len();
+        let cells = rows.into_iter().flat_map(|(_, row)| row).collect();
+        Ok(Grid::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    /// The cell at `(x, y)`, or `None` off the edge of the grid.
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        if self.in_bounds(x, y) {
+            Some(&self.cells[y as usize * self.width + x as usize])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        if self.in_bounds(x, y) {
+            Some(&mut self.cells[y as usize * self.width + x as usize])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `(x, y)` on a grid that repeats forever in every
+    /// direction.
+    pub fn get_wrapping(&self, x: i64, y: i64) -> &T {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+        &self[(x, y)]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    fn offset_positions<'a>(
+        &'a self,
+        (x, y): (usize, usize),
+        offsets: &'static [(i64, i64)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .iter()
+            .map(move |(dx, dy)| (x as i64 + dx, y as i64 + dy))
+            .filter(move |&(x, y)| self.in_bounds(x, y))
+            .map(|(x, y)| (x as usize, y as usize))
+    }
+
+    /// Positions of the up to eight cells around `(x, y)`.
+    pub fn neighbors(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offset_positions((x, y), &NEIGHBORS)
+    }
+
+    /// Positions of the up to four cells sharing an edge with `(x, y)`.
+    pub fn orthogonal_neighbors(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offset_positions((x, y), &ORTHOGONAL_NEIGHBORS)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Builds a `width` by `height` grid by looking up each new cell in this
+    /// one.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+        Grid::new(width, height, cells)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Mirrors the grid along the diagonal from the top left.
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    pub fn flip_lr(&self) -> Grid<T> {
+        let w = self.width;
+        self.remap(self.width, self.height, |x, y| (w - 1 - x, y))
+    }
+
+    pub fn flip_ud(&self) -> Grid<T> {
+        let h = self.height;
+        self.remap(self.width, self.height, |x, y| (x, h - 1 - y))
+    }
+
+    pub fn rotate_cw(&self) -> Grid<T> {
+        let h = self.height;
+        self.remap(self.height, self.width, |x, y| (y, h - 1 - x))
+    }
+
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        let w = self.width;
+        self.remap(self.height, self.width, |x, y| (w - 1 - y, x))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is off the grid",
+            x,
+            y
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is off the grid",
+            x,
+            y
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(input: &str) -> Grid<char> {
+        Grid::parse(input, Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = chars("ab\ncd\nef\n");
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.to_string(), "ab\ncd\nef\n");
+
+        let err = Grid::parse("..\n.x", |c| if c == '.' { Some(()) } else { None });
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "line 2, column 2: unexpected character `x`"
+        );
+        let err = Grid::parse("..\n...", Some).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a row of width 2"
+        );
+        let err = Grid::parse("\n", Some).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: missing grid");
+    }
+
+    #[test]
+    fn test_access() {
+        let grid = chars("ab\ncd");
+        assert_eq!(grid.get(1, 0), Some(&'b'));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, -1), None);
+        assert_eq!(*grid.get_wrapping(2, -1), 'c');
+        assert_eq!(*grid.get_wrapping(-3, 5), 'd');
+        assert_eq!(grid.column(1).collect::<String>(), "bd");
+        assert_eq!(grid.positions().nth(2), Some(((0, 1), &'c')));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(grid.neighbors(1, 1).count(), 8);
+        assert_eq!(
+            grid.neighbors(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1), (1, 1)]
+        );
+        assert_eq!(
+            grid.orthogonal_neighbors(2, 1).collect::<Vec<_>>(),
+            vec![(2, 0), (1, 1), (2, 2)]
+        );
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = chars("abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.flip_lr().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_ud().to_string(), "def\nabc\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase())[(2, 1)], 'F');
+    }
+}
@@ -0,0 +1,132 @@
+#[macro_use]
+pub mod log;
+
+pub mod answers;
+pub mod aoc_01;
+pub mod aoc_02;
+pub mod aoc_03;
+pub mod aoc_04;
+pub mod aoc_05;
+pub mod aoc_06;
+pub mod aoc_07;
+pub mod aoc_08;
+pub mod aoc_09;
+pub mod aoc_10;
+pub mod aoc_11;
+pub mod aoc_12;
+pub mod aoc_13;
+pub mod aoc_14;
+pub mod aoc_15;
+pub mod aoc_16;
+pub mod aoc_17;
+pub mod aoc_18;
+pub mod aoc_19;
+pub mod aoc_20;
+pub mod aoc_21;
+pub mod aoc_22;
+pub mod aoc_23;
+pub mod aoc_24;
+pub mod aoc_25;
+pub mod automaton;
+pub mod bench;
+pub mod csv;
+pub mod differential;
+pub mod error;
+pub mod grid;
+pub mod json;
+pub mod report;
+pub mod rng;
+pub mod solution;
+
+use solution::DynSolution;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Which parts of a day the runner should solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    Both,
+}
+
+impl Part {
+    pub fn one(self) -> bool {
+        self != Part::Two
+    }
+
+    pub fn two(self) -> bool {
+        self != Part::One
+    }
+}
+
+/// Every day's solution, indexed by `day - 1`.
+pub static SOLUTIONS: [&dyn DynSolution; 25] = [
+    &aoc_01::Day01,
+    &aoc_02::Day02,
+    &aoc_03::Day03,
+    &aoc_04::Day04,
+    &aoc_05::Day05,
+    &aoc_06::Day06,
+    &aoc_07::Day07,
+    &aoc_08::Day08,
+    &aoc_09::Day09 { preamble: 25 },
+    &aoc_10::Day10,
+    &aoc_11::Day11,
+    &aoc_12::Day12,
+    &aoc_13::Day13,
+    &aoc_14::Day14,
+    &aoc_15::Day15,
+    &aoc_16::Day16,
+    &aoc_17::Day17,
+    &aoc_18::Day18,
+    &aoc_19::Day19,
+    &aoc_20::Day20,
+    &aoc_21::Day21,
+    &aoc_22::Day22,
+    &aoc_23::Day23,
+    &aoc_24::Day24,
+    &aoc_25::Day25,
+];
+
+/// The solution for `day`, if there is one.
+pub fn solution(day: usize) -> Option<&'static dyn DynSolution> {
+    day.checked_sub(1).and_then(|i| SOLUTIONS.get(i)).copied()
+}
+
+/// Resolves a path inside the checkout, looking in the current directory first
+/// and falling back to the crate root so the runner works from anywhere.
+fn locate(relative: PathBuf) -> PathBuf {
+    if relative.exists() {
+        relative
+    } else {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(relative)
+    }
+}
+
+/// Default puzzle input for a day, e.g. `inputs/input_07`.
+pub fn input_path(day: usize) -> PathBuf {
+    locate(PathBuf::from(format!("inputs/input_{:02}", day)))
+}
+
+/// Reads a puzzle input, treating `-` as stdin.
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// The recorded answers for the inputs in `inputs/`.
+pub fn answers_path() -> PathBuf {
+    locate(PathBuf::from("answers.toml"))
+}
+
+/// Where `aoc bench` keeps the last results to compare against.
+pub fn bench_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("target/aoc-bench.tsv")
+}
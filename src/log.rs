@@ -0,0 +1,112 @@
+//! Opt-in diagnostics on stderr, filtered by level and by day.
+//!
+//! Days log through the `info!`, `debug!` and `trace!` macros, which tag each
+//! message with the calling module so that output can be limited to
+//! particular days. Checking whether a message is wanted loads the verbosity
+//! and, if the level is on, the day mask, then reads the day from the
+//! module path, so a message whose level is off costs one atomic load.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU32, AtomicU8, Ordering};
+
+/// How important a message is. Messages are shown when their level is at or
+/// below the verbosity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Shown unless `-q` is given.
+    Info = 1,
+    /// Shown with `-v`.
+    Debug = 2,
+    /// Shown with `-vv`; may be very noisy.
+    Trace = 3,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        })
+    }
+}
+
+/// 0 is `-q`, 1 the default, 2 `-v` and 3 `-vv`.
+static VERBOSITY: AtomicU8 = AtomicU8::new(Level::Info as u8);
+/// Bit `d` set means day `d` may log; 0 means every day may.
+static DAYS: AtomicU32 = AtomicU32::new(0);
+
+pub fn set_verbosity(verbosity: u8) {
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+/// Limits logging from days to `days`; an empty slice lifts the limit.
+/// Messages from outside the days are unaffected.
+pub fn set_days(days: &[usize]) {
+    let mask = days.iter().fold(0, |mask, &d| mask | 1 << d);
+    DAYS.store(mask, Ordering::Relaxed);
+}
+
+/// The day a module path such as `advent_of_code_2020::aoc_19` belongs to.
+fn day_of(target: &str) -> Option<usize> {
+    target
+        .rsplit("::")
+        .find_map(|segment| segment.strip_prefix("aoc_"))
+        .and_then(|day| day.parse().ok())
+}
+
+#[inline]
+pub fn enabled(level: Level, target: &str) -> bool {
+    level as u8 <= VERBOSITY.load(Ordering::Relaxed) && day_enabled(target)
+}
+
+fn day_enabled(target: &str) -> bool {
+    let mask = DAYS.load(Ordering::Relaxed);
+    match day_of(target) {
+        Some(day) if mask != 0 => day < 32 && mask & 1 << day != 0,
+        _ => true,
+    }
+}
+
+/// Writes a message unconditionally; use the macros instead.
+pub fn write(level: Level, target: &str, args: fmt::Arguments) {
+    let module = target.rsplit("::").next().unwrap_or(target);
+    eprintln!("[{} {}] {}", level, module, args);
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_of() {
+        assert_eq!(day_of("advent_of_code_2020::aoc_19"), Some(19));
+        assert_eq!(day_of("advent_of_code_2020::aoc_07::tests"), Some(7));
+        assert_eq!(day_of("advent_of_code_2020::report"), None);
+        assert_eq!(day_of("aoc"), None);
+    }
+}
@@ -0,0 +1,327 @@
+use crate::error::AocError;
+use crate::json;
+use crate::solution::{Answer, DynSolution};
+use crate::Part;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Why a day produced no answers.
+#[derive(Debug, Clone)]
+pub enum ReportError {
+    Read(String),
+    Parse(AocError),
+}
+
+impl fmt::Display for ReportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReportError::Read(e) => write!(f, "couldn't read input: {}", e),
+            ReportError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// Everything the runner learned from solving one day for one input.
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: usize,
+    pub path: PathBuf,
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartReport>,
+    pub error: Option<ReportError>,
+}
+
+impl DayReport {
+    pub fn failed(day: usize, path: &Path, error: ReportError) -> DayReport {
+        DayReport {
+            day,
+            path: path.to_path_buf(),
+            parse_elapsed: Duration::default(),
+            parts: Vec::new(),
+            error: Some(error),
+        }
+    }
+}
+
+/// Parses `input` and solves the requested parts, timing each step.
+pub fn solve(day: usize, path: &Path, input: &str, part: Part) -> DayReport {
+    let solution = crate::solution(day).expect("no solution for day");
+    solve_with(solution, day, path, input, part)
+}
+
+/// Like `solve`, but with `solution` in place of the day's own.
+pub fn solve_with(
+    solution: &dyn DynSolution,
+    day: usize,
+    path: &Path,
+    input: &str,
+    part: Part,
+) -> DayReport {
+    let start = Instant::now();
+    let parsed = match solution.parse_dyn(input) {
+        Ok(x) => x,
+        Err(e) => return DayReport::failed(day, path, ReportError::Parse(e)),
+    };
+    let mut report = DayReport {
+        day,
+        path: path.to_path_buf(),
+        parse_elapsed: start.elapsed(),
+        parts: Vec::new(),
+        error: None,
+    };
+
+    let mut timed = |part: u8, f: &dyn Fn() -> Answer| {
+        let start = Instant::now();
+        let answer = f();
+        report.parts.push(PartReport {
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        });
+    };
+    if part.one() {
+        timed(1, &|| solution.part1_dyn(parsed.as_ref()));
+    }
+    if part.two() {
+        timed(2, &|| solution.part2_dyn(parsed.as_ref()));
+    }
+    report
+}
+
+/// Prints `err` along with the offending input line and a caret under the
+/// column it points at.
+pub fn print_parse_error(label: &str, path: &Path, input: &str, err: &AocError) {
+    eprintln!(
+        "{}: {}:{}:{}: {}",
+        label,
+        path.display(),
+        err.line,
+        err.column,
+        err.kind
+    );
+    if let Some(line) = input.lines().nth(err.line - 1) {
+        let gutter = err.line.to_string().len();
+        eprintln!("{} |", " ".repeat(gutter));
+        eprintln!("{} | {}", err.line, line);
+        eprintln!("{} | {}^", " ".repeat(gutter), " ".repeat(err.column - 1));
+    }
+}
+
+/// Somewhere to send the runner's results.
+pub trait Reporter {
+    /// Reports one solved (or failed) day. `input` is the text the day was
+    /// given, for pointing at parse errors.
+    fn report(&mut self, report: &DayReport, input: &str);
+
+    /// Called once every day has been reported.
+    fn finish(&mut self) {}
+}
+
+fn print_error(report: &DayReport, input: &str) {
+    let label = format!("day {:02}", report.day);
+    match &report.error {
+        Some(ReportError::Parse(e)) => print_parse_error(&label, &report.path, input, e),
+        Some(e) => eprintln!("{}: {}: {}", label, report.path.display(), e),
+        None => (),
+    }
+}
+
+/// Human-readable output: a line per part, errors on stderr.
+pub struct TextReporter {
+    /// Print a `== day NN ==` header before each day.
+    pub headers: bool,
+    /// Name the input in each header too, e.g. `== day 04, example 2 ==`,
+    /// for days run on more than one.
+    pub inputs: bool,
+}
+
+impl TextReporter {
+    fn header(&self, report: &DayReport) -> String {
+        if self.inputs {
+            format!("== day {:02}, {} ==", report.day, report.path.display())
+        } else {
+            format!("== day {:02} ==", report.day)
+        }
+    }
+}
+
+impl Reporter for TextReporter {
+    fn report(&mut self, report: &DayReport, input: &str) {
+        if report.error.is_some() {
+            print_error(report, input);
+            return;
+        }
+        if self.headers {
+            println!("{}", self.header(report));
+        }
+        for p in report.parts.iter() {
+            println!("part {}: {}", p.part, p.answer);
+        }
+    }
+}
+
+/// Human-readable output for one day over many inputs: a row per input and a
+/// column per part, printed once every input is in.
+pub struct TableReporter {
+    parts: Vec<u8>,
+    rows: Vec<Vec<String>>,
+}
+
+impl TableReporter {
+    pub fn new(part: Part) -> TableReporter {
+        TableReporter {
+            parts: [1, 2]
+                .iter()
+                .copied()
+                .filter(|&p| if p == 1 { part.one() } else { part.two() })
+                .collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// The table so far: inputs left-aligned, answers right-aligned.
+    pub fn table(&self) -> String {
+        let mut header = vec!["input".to_string()];
+        header.extend(self.parts.iter().map(|p| format!("part {}", p)));
+        let rows: Vec<&Vec<String>> = std::iter::once(&header).chain(self.rows.iter()).collect();
+        let widths: Vec<usize> = (0..header.len())
+            .map(|i| rows.iter().map(|r| r[i].chars().count()).max().unwrap())
+            .collect();
+        let mut table = String::new();
+        for r in rows.iter() {
+            let mut line = format!("{:<w$}", r[0], w = widths[0]);
+            for (cell, w) in r.iter().zip(widths.iter()).skip(1) {
+                line += &format!("  {:>w$}", cell, w = w);
+            }
+            table += line.trim_end();
+            table.push('\n');
+        }
+        table
+    }
+}
+
+impl Reporter for TableReporter {
+    fn report(&mut self, report: &DayReport, input: &str) {
+        print_error(report, input);
+        let mut row = vec![report.path.display().to_string()];
+        for &part in self.parts.iter() {
+            row.push(match report.parts.iter().find(|p| p.part == part) {
+                Some(p) => p.answer.to_string(),
+                None => "error".to_string(),
+            });
+        }
+        self.rows.push(row);
+    }
+
+    fn finish(&mut self) {
+        print!("{}", self.table());
+    }
+}
+
+fn answer_json(answer: &Answer) -> String {
+    match answer {
+        Answer::Int(x) => x.to_string(),
+        Answer::Str(s) => json::string(s),
+        Answer::Unsolved => "null".to_string(),
+    }
+}
+
+/// Encodes a report as a single-line JSON object.
+pub fn to_json(report: &DayReport) -> String {
+    let parts = report.parts.iter().map(|p| {
+        json::Object::new()
+            .field("part", p.part.to_string())
+            .field("answer", answer_json(&p.answer))
+            .field("elapsed_ns", p.elapsed.as_nanos().to_string())
+            .to_string()
+    });
+    json::Object::new()
+        .field("day", report.day.to_string())
+        .field("input", json::string(&report.path.to_string_lossy()))
+        .field("parse_ns", report.parse_elapsed.as_nanos().to_string())
+        .field("parts", json::array(parts))
+        .field(
+            "error",
+            match &report.error {
+                Some(e) => json::string(&e.to_string()),
+                None => "null".to_string(),
+            },
+        )
+        .to_string()
+}
+
+/// JSON Lines output: one object per day on stdout.
+pub struct JsonReporter;
+
+impl Reporter for JsonReporter {
+    fn report(&mut self, report: &DayReport, _input: &str) {
+        println!("{}", to_json(report));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let path = Path::new("inputs/input_12");
+        let mut report = solve(12, path, "F10\nN3\nF7\nR90\nF11", Part::Both);
+        assert!(report.error.is_none());
+        assert_eq!(report.parts.len(), 2);
+        assert_eq!(report.parts[1].answer, Answer::Int(286));
+
+        report.parse_elapsed = Duration::from_nanos(5);
+        report.parts.truncate(1);
+        report.parts[0].elapsed = Duration::from_nanos(7);
+        assert_eq!(
+            to_json(&report),
+            r#"{"day":12,"input":"inputs/input_12","parse_ns":5,"parts":[{"part":1,"answer":25,"elapsed_ns":7}],"error":null}"#
+        );
+
+        let report = solve(12, path, "F10\nN3x", Part::One);
+        assert!(to_json(&report)
+            .ends_with(r#""parts":[],"error":"line 2, column 2: invalid number `3x`"}"#));
+    }
+
+    #[test]
+    fn test_header() {
+        let report = solve(12, Path::new("example 2"), "F10", Part::One);
+        let mut text = TextReporter {
+            headers: true,
+            inputs: false,
+        };
+        assert_eq!(text.header(&report), "== day 12 ==");
+        text.inputs = true;
+        assert_eq!(text.header(&report), "== day 12, example 2 ==");
+    }
+
+    #[test]
+    fn test_table() {
+        let mut table = TableReporter::new(Part::Both);
+        table.report(
+            &solve(12, Path::new("a"), "F10\nN3\nF7\nR90\nF11", Part::Both),
+            "",
+        );
+        table.report(&solve(12, Path::new("longer"), "F1", Part::Both), "");
+        table.report(
+            &DayReport::failed(12, Path::new("-"), ReportError::Read("gone".into())),
+            "",
+        );
+        assert_eq!(
+            table.table(),
+            "input   part 1  part 2\n\
+             a           25     286\n\
+             longer       1      11\n\
+             -        error   error\n"
+        );
+    }
+}
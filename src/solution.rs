@@ -0,0 +1,238 @@
+use crate::answers::Expected;
+use crate::error::Result;
+use crate::rng::Rng;
+use std::any::Any;
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Str(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(x) => write!(f, "{}", x),
+            Answer::Str(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(x: i64) -> Answer {
+        Answer::Int(x)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(x: i32) -> Answer {
+        Answer::Int(x as i64)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(x: usize) -> Answer {
+        Answer::Int(x as i64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(x: u32) -> Answer {
+        Answer::Int(x as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(x: Option<T>) -> Answer {
+        match x {
+            Some(x) => x.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+/// An example input from the puzzle text, with the answers it should give.
+#[derive(Clone)]
+pub struct Example {
+    pub input: &'static str,
+    pub expected: Expected,
+    /// Solves the example in place of the day's own solution, for examples
+    /// that use different settings from the real puzzle.
+    pub solver: Option<&'static dyn DynSolution>,
+}
+
+impl Example {
+    pub fn new(input: &'static str) -> Example {
+        Example {
+            input,
+            expected: Expected::default(),
+            solver: None,
+        }
+    }
+
+    pub fn solver(mut self, solver: &'static dyn DynSolution) -> Example {
+        self.solver = Some(solver);
+        self
+    }
+
+    /// The solution to run the example with, given that it's from `day`.
+    pub fn solution(&self, day: usize) -> &'static dyn DynSolution {
+        self.solver
+            .unwrap_or_else(|| crate::solution(day).expect("no solution for day"))
+    }
+
+    pub fn part1(mut self, answer: impl Into<Answer>) -> Example {
+        self.expected.part1 = Some(answer.into());
+        self
+    }
+
+    pub fn part2(mut self, answer: impl Into<Answer>) -> Example {
+        self.expected.part2 = Some(answer.into());
+        self
+    }
+}
+
+/// A randomly generated input, with the answers it should give where the
+/// generator knows them.
+#[derive(Debug, Clone)]
+pub struct Generated {
+    pub input: String,
+    pub expected: Expected,
+}
+
+impl Generated {
+    pub fn new(input: String) -> Generated {
+        Generated {
+            input,
+            expected: Expected::default(),
+        }
+    }
+
+    pub fn part1(mut self, answer: impl Into<Answer>) -> Generated {
+        self.expected.part1 = Some(answer.into());
+        self
+    }
+
+    pub fn part2(mut self, answer: impl Into<Answer>) -> Generated {
+        self.expected.part2 = Some(answer.into());
+        self
+    }
+}
+
+/// A day's puzzle: parse the input once, then answer each part from it.
+pub trait Solution {
+    type Parsed: 'static;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed>;
+
+    fn part1(&self, parsed: &Self::Parsed) -> Answer;
+
+    fn part2(&self, _parsed: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+
+    /// The examples from the puzzle text that this solution can run.
+    fn examples(&self) -> Vec<Example> {
+        Vec::new()
+    }
+
+    /// A random input in this day's format, roughly `size` items (lines,
+    /// passports, tiles, ...) big.
+    fn generate(&self, _rng: &mut Rng, _size: usize) -> Option<Generated> {
+        None
+    }
+}
+
+/// Object-safe view of a `Solution`, so days with different parsed types can
+/// live in the same registry.
+pub trait DynSolution: Sync {
+    fn parse_dyn(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    fn part1_dyn(&self, parsed: &dyn Any) -> Answer;
+
+    fn part2_dyn(&self, parsed: &dyn Any) -> Answer;
+
+    fn examples_dyn(&self) -> Vec<Example>;
+
+    fn generate_dyn(&self, rng: &mut Rng, size: usize) -> Option<Generated>;
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> &S::Parsed {
+    parsed
+        .downcast_ref::<S::Parsed>()
+        .expect("parsed input belongs to a different day")
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn parse_dyn(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn part1_dyn(&self, parsed: &dyn Any) -> Answer {
+        self.part1(downcast::<S>(parsed))
+    }
+
+    fn part2_dyn(&self, parsed: &dyn Any) -> Answer {
+        self.part2(downcast::<S>(parsed))
+    }
+
+    fn examples_dyn(&self) -> Vec<Example> {
+        self.examples()
+    }
+
+    fn generate_dyn(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        self.generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{parse_lines, parse_num};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<i64>;
+
+        fn parse(&self, input: &str) -> Result<Vec<i64>> {
+            parse_lines(input, |line| parse_num(line, line))
+        }
+
+        fn part1(&self, parsed: &Vec<i64>) -> Answer {
+            parsed.iter().sum::<i64>().into()
+        }
+    }
+
+    #[test]
+    fn test_dyn() {
+        let s: &dyn DynSolution = &Sum;
+        let parsed = s.parse_dyn("1\n2\n3").unwrap();
+        assert_eq!(s.part1_dyn(parsed.as_ref()), Answer::Int(6));
+        assert_eq!(s.part2_dyn(parsed.as_ref()), Answer::Unsolved);
+        assert_eq!(s.parse_dyn("1\nx").err().unwrap().line, 2);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42usize).to_string(), "42");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::from(None::<i64>).to_string(), "unsolved");
+    }
+}
@@ -0,0 +1,64 @@
+//! Checks every day against the answers recorded in `answers.toml`.
+
+use advent_of_code_2020::answers::{parse_answers, verify_day};
+use advent_of_code_2020::{answers_path, input_path};
+use std::fs;
+
+fn check(day: usize) {
+    let answers = parse_answers(&fs::read_to_string(answers_path()).unwrap()).unwrap();
+    let expected = answers
+        .get(&day)
+        .unwrap_or_else(|| panic!("answers.toml has no answers for day {}", day));
+    let input = fs::read_to_string(input_path(day)).unwrap();
+    for c in verify_day(day, &input, expected).unwrap() {
+        assert!(
+            c.ok(),
+            "day {} part {}: expected {}, got {}",
+            day,
+            c.part,
+            c.expected,
+            c.actual
+        );
+    }
+}
+
+macro_rules! days {
+    ($($(#[$attr:meta])* $name:ident: $day:expr,)*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                check($day);
+            }
+        )*
+    };
+}
+
+days! {
+    #[ignore = "inputs/input_01 is a copy of day 2's input, so day 1 has no answers"]
+    day01: 1,
+    day02: 2,
+    day03: 3,
+    day04: 4,
+    day05: 5,
+    day06: 6,
+    day07: 7,
+    day08: 8,
+    day09: 9,
+    day10: 10,
+    day11: 11,
+    day12: 12,
+    day13: 13,
+    day14: 14,
+    day15: 15,
+    day16: 16,
+    day17: 17,
+    day18: 18,
+    day19: 19,
+    day20: 20,
+    day21: 21,
+    day22: 22,
+    day23: 23,
+    day24: 24,
+    day25: 25,
+}
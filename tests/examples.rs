@@ -0,0 +1,26 @@
+//! Checks every day against the examples from its puzzle text.
+
+use advent_of_code_2020::answers::verify_with;
+use advent_of_code_2020::SOLUTIONS;
+
+#[test]
+fn examples() {
+    for (i, solution) in SOLUTIONS.iter().enumerate() {
+        let day = i + 1;
+        for (n, example) in solution.examples_dyn().iter().enumerate() {
+            let checks = verify_with(example.solution(day), example.input, &example.expected)
+                .unwrap_or_else(|e| panic!("day {} example {}: {}", day, n + 1, e));
+            for c in checks {
+                assert!(
+                    c.ok(),
+                    "day {} example {} part {}: expected {}, got {}",
+                    day,
+                    n + 1,
+                    c.part,
+                    c.expected,
+                    c.actual
+                );
+            }
+        }
+    }
+}
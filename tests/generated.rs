@@ -0,0 +1,35 @@
+//! Checks every day against inputs from its generator, for a few seeds and
+//! sizes.
+
+use advent_of_code_2020::answers::verify_day;
+use advent_of_code_2020::rng::Rng;
+use advent_of_code_2020::SOLUTIONS;
+
+#[test]
+fn generated_inputs() {
+    for (i, s) in SOLUTIONS.iter().enumerate() {
+        let day = i + 1;
+        for seed in 0..5 {
+            for &size in [1, 10, 100].iter() {
+                let generated = match s.generate_dyn(&mut Rng::new(seed), size) {
+                    Some(x) => x,
+                    None => continue,
+                };
+                let checks = verify_day(day, &generated.input, &generated.expected)
+                    .unwrap_or_else(|e| panic!("day {:02} seed {}: {}", day, seed, e));
+                for c in checks.iter() {
+                    assert!(
+                        c.ok(),
+                        "day {:02} seed {} size {} part {}: expected {}, got {}",
+                        day,
+                        seed,
+                        size,
+                        c.part,
+                        c.expected,
+                        c.actual
+                    );
+                }
+            }
+        }
+    }
+}